@@ -32,6 +32,39 @@ pub mod anchor_counter {
         Ok(())
     }
 
+    /// Increment every counter passed in `remaining_accounts` by 1
+    ///
+    /// The Anchor counterpart of the native `BatchIncrement`: the counters aren't
+    /// in the accounts struct, so each one is deserialized, checked against the
+    /// authority and written back by hand.
+    pub fn increment_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, IncrementMany<'info>>,
+    ) -> Result<()> {
+        for account_info in ctx.remaining_accounts {
+            require!(
+                account_info.is_writable,
+                anchor_lang::error::ErrorCode::ConstraintMut
+            );
+            let mut counter = Account::<Counter>::try_from(account_info)?;
+            require_keys_eq!(
+                counter.authority,
+                ctx.accounts.authority.key(),
+                anchor_lang::error::ErrorCode::ConstraintHasOne
+            );
+
+            counter.count = counter
+                .count
+                .checked_add(1)
+                .ok_or(ErrorCode::CounterOverflow)?;
+
+            // Remaining accounts aren't persisted automatically
+            counter.exit(&crate::ID)?;
+        }
+
+        msg!("Incremented {} counters", ctx.remaining_accounts.len());
+        Ok(())
+    }
+
     /// Perform a CPI to increment a native counter
     /// This demonstrates how to call a native Solana program from Anchor
     pub fn increment_native_counter(ctx: Context<IncrementNativeCounter>) -> Result<()> {
@@ -79,12 +112,17 @@ pub struct InitializeCounter<'info> {
 
 #[derive(Accounts)]
 pub struct IncrementCounter<'info> {
-    #[account(mut)]
+    #[account(mut, has_one = authority)]
     pub counter: Account<'info, Counter>,
 
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct IncrementMany<'info> {
+    pub authority: Signer<'info>,
+}
+
 #[derive(Accounts)]
 pub struct IncrementNativeCounter<'info> {
     /// CHECK: This is the native counter account from the native program
@@ -13,10 +13,20 @@ import {
   decodeAccount,
   fetchEncodedAccount,
   fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getTupleDecoder,
+  getTupleEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Account,
   type Address,
   type EncodedAccount,
@@ -29,16 +39,66 @@ import {
   type MaybeEncodedAccount,
 } from '@solana/kit';
 
-export type CounterAccount = { count: bigint };
+export type CounterAccount = {
+  count: bigint;
+  step: bigint;
+  lastUpdated: bigint;
+  maxValue: bigint;
+  authority: Address;
+  enabled: boolean;
+  snapshot: bigint;
+  /** Tag (`true` when set) and key, zeroed when unset: the program's `Option<Pubkey>` at a fixed size */
+  delegate: readonly [boolean, Address];
+  lastEpoch: bigint;
+  overflowBehavior: number;
+  nonce: bigint;
+};
 
-export type CounterAccountArgs = { count: number | bigint };
+export type CounterAccountArgs = {
+  count: number | bigint;
+  step: number | bigint;
+  lastUpdated: number | bigint;
+  maxValue: number | bigint;
+  authority: Address;
+  enabled: boolean;
+  snapshot: number | bigint;
+  /** Tag (`true` when set) and key, zeroed when unset: the program's `Option<Pubkey>` at a fixed size */
+  delegate: readonly [boolean, Address];
+  lastEpoch: number | bigint;
+  overflowBehavior: number;
+  nonce: number | bigint;
+};
 
 export function getCounterAccountEncoder(): FixedSizeEncoder<CounterAccountArgs> {
-  return getStructEncoder([['count', getU64Encoder()]]);
+  return getStructEncoder([
+    ['count', getU64Encoder()],
+    ['step', getU64Encoder()],
+    ['lastUpdated', getI64Encoder()],
+    ['maxValue', getU64Encoder()],
+    ['authority', getAddressEncoder()],
+    ['enabled', getBooleanEncoder()],
+    ['snapshot', getU64Encoder()],
+    ['delegate', getTupleEncoder([getBooleanEncoder(), getAddressEncoder()])],
+    ['lastEpoch', getU64Encoder()],
+    ['overflowBehavior', getU8Encoder()],
+    ['nonce', getU64Encoder()],
+  ]);
 }
 
 export function getCounterAccountDecoder(): FixedSizeDecoder<CounterAccount> {
-  return getStructDecoder([['count', getU64Decoder()]]);
+  return getStructDecoder([
+    ['count', getU64Decoder()],
+    ['step', getU64Decoder()],
+    ['lastUpdated', getI64Decoder()],
+    ['maxValue', getU64Decoder()],
+    ['authority', getAddressDecoder()],
+    ['enabled', getBooleanDecoder()],
+    ['snapshot', getU64Decoder()],
+    ['delegate', getTupleDecoder([getBooleanDecoder(), getAddressDecoder()])],
+    ['lastEpoch', getU64Decoder()],
+    ['overflowBehavior', getU8Decoder()],
+    ['nonce', getU64Decoder()],
+  ]);
 }
 
 export function getCounterAccountCodec(): FixedSizeCodec<
@@ -6,4 +6,11 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './bigCounterAccount';
+export * from './commitmentAccount';
 export * from './counterAccount';
+export * from './historyCounterAccount';
+export * from './indexedCounterAccount';
+export * from './namedCounterAccount';
+export * from './statsAccount';
+export * from './whitelistCounterAccount';
@@ -17,20 +17,65 @@ import { COUNTER_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 export const COUNTER_PROGRAM_ERROR__INVALID_INSTRUCTION_DATA = 0x0; // 0
 export const COUNTER_PROGRAM_ERROR__COUNTER_OVERFLOW = 0x1; // 1
 export const COUNTER_PROGRAM_ERROR__INCORRECT_PROGRAM_ID = 0x2; // 2
+export const COUNTER_PROGRAM_ERROR__ALREADY_INITIALIZED = 0x3; // 3
+export const COUNTER_PROGRAM_ERROR__RATE_LIMITED = 0x4; // 4
+export const COUNTER_PROGRAM_ERROR__COUNTER_UNDERFLOW = 0x5; // 5
+export const COUNTER_PROGRAM_ERROR__UNEXPECTED_ACCOUNT_COUNT = 0x6; // 6
+export const COUNTER_PROGRAM_ERROR__INVALID_AUTHORITY = 0x7; // 7
+export const COUNTER_PROGRAM_ERROR__COUNTER_IS_PAYER = 0x8; // 8
+export const COUNTER_PROGRAM_ERROR__COUNTER_DISABLED = 0x9; // 9
+export const COUNTER_PROGRAM_ERROR__PROGRAM_NOT_EXECUTABLE = 0xa; // 10
+export const COUNTER_PROGRAM_ERROR__FEATURE_DISABLED = 0xb; // 11
+export const COUNTER_PROGRAM_ERROR__CAS_MISMATCH = 0xc; // 12
+export const COUNTER_PROGRAM_ERROR__INVALID_MERKLE_PROOF = 0xd; // 13
+export const COUNTER_PROGRAM_ERROR__NO_COMMITMENT = 0xe; // 14
+export const COUNTER_PROGRAM_ERROR__REVEAL_MISMATCH = 0xf; // 15
+export const COUNTER_PROGRAM_ERROR__MISSING_ACCOUNT = 0x10; // 16
+export const COUNTER_PROGRAM_ERROR__STALE_NONCE = 0x11; // 17
 
 export type CounterProgramError =
+  | typeof COUNTER_PROGRAM_ERROR__ALREADY_INITIALIZED
+  | typeof COUNTER_PROGRAM_ERROR__CAS_MISMATCH
+  | typeof COUNTER_PROGRAM_ERROR__COUNTER_DISABLED
+  | typeof COUNTER_PROGRAM_ERROR__COUNTER_IS_PAYER
   | typeof COUNTER_PROGRAM_ERROR__COUNTER_OVERFLOW
+  | typeof COUNTER_PROGRAM_ERROR__COUNTER_UNDERFLOW
+  | typeof COUNTER_PROGRAM_ERROR__FEATURE_DISABLED
   | typeof COUNTER_PROGRAM_ERROR__INCORRECT_PROGRAM_ID
-  | typeof COUNTER_PROGRAM_ERROR__INVALID_INSTRUCTION_DATA;
+  | typeof COUNTER_PROGRAM_ERROR__INVALID_AUTHORITY
+  | typeof COUNTER_PROGRAM_ERROR__INVALID_INSTRUCTION_DATA
+  | typeof COUNTER_PROGRAM_ERROR__INVALID_MERKLE_PROOF
+  | typeof COUNTER_PROGRAM_ERROR__MISSING_ACCOUNT
+  | typeof COUNTER_PROGRAM_ERROR__NO_COMMITMENT
+  | typeof COUNTER_PROGRAM_ERROR__PROGRAM_NOT_EXECUTABLE
+  | typeof COUNTER_PROGRAM_ERROR__RATE_LIMITED
+  | typeof COUNTER_PROGRAM_ERROR__REVEAL_MISMATCH
+  | typeof COUNTER_PROGRAM_ERROR__STALE_NONCE
+  | typeof COUNTER_PROGRAM_ERROR__UNEXPECTED_ACCOUNT_COUNT;
 
 let counterProgramErrorMessages:
   | Record<CounterProgramError, string>
   | undefined;
 if (process.env.NODE_ENV !== 'production') {
   counterProgramErrorMessages = {
+    [COUNTER_PROGRAM_ERROR__ALREADY_INITIALIZED]: `Counter account is already initialized`,
+    [COUNTER_PROGRAM_ERROR__CAS_MISMATCH]: `Counter does not hold the expected value`,
+    [COUNTER_PROGRAM_ERROR__COUNTER_DISABLED]: `Counter is disabled`,
+    [COUNTER_PROGRAM_ERROR__COUNTER_IS_PAYER]: `Counter account and payer must be different accounts`,
     [COUNTER_PROGRAM_ERROR__COUNTER_OVERFLOW]: `Counter overflow occurred`,
+    [COUNTER_PROGRAM_ERROR__COUNTER_UNDERFLOW]: `Counter underflow occurred`,
+    [COUNTER_PROGRAM_ERROR__FEATURE_DISABLED]: `Instruction requires a feature this program was built without`,
     [COUNTER_PROGRAM_ERROR__INCORRECT_PROGRAM_ID]: `Incorrect program ID`,
+    [COUNTER_PROGRAM_ERROR__INVALID_AUTHORITY]: `Signer is not the counter authority`,
     [COUNTER_PROGRAM_ERROR__INVALID_INSTRUCTION_DATA]: `Invalid instruction data provided`,
+    [COUNTER_PROGRAM_ERROR__INVALID_MERKLE_PROOF]: `Merkle proof does not match the whitelist root`,
+    [COUNTER_PROGRAM_ERROR__MISSING_ACCOUNT]: `A required account is missing`,
+    [COUNTER_PROGRAM_ERROR__NO_COMMITMENT]: `No pending commitment to reveal`,
+    [COUNTER_PROGRAM_ERROR__PROGRAM_NOT_EXECUTABLE]: `Program account is not executable`,
+    [COUNTER_PROGRAM_ERROR__RATE_LIMITED]: `Counter was updated too recently`,
+    [COUNTER_PROGRAM_ERROR__REVEAL_MISMATCH]: `Revealed nonce does not match the commitment`,
+    [COUNTER_PROGRAM_ERROR__STALE_NONCE]: `Nonce is not above the last one accepted`,
+    [COUNTER_PROGRAM_ERROR__UNEXPECTED_ACCOUNT_COUNT]: `More accounts than the instruction takes`,
   };
 }
 
@@ -14,6 +14,7 @@ import {
   getU8Encoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -21,8 +22,11 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { COUNTER_PROGRAM_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -36,6 +40,11 @@ export function getIncrementCounterDiscriminatorBytes() {
 export type IncrementCounterInstruction<
   TProgram extends string = typeof COUNTER_PROGRAM_PROGRAM_ADDRESS,
   TAccountCounter extends string | AccountMeta<string> = string,
+  TAccountPayer extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountStats extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -44,6 +53,16 @@ export type IncrementCounterInstruction<
       TAccountCounter extends string
         ? WritableAccount<TAccountCounter>
         : TAccountCounter,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            AccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      TAccountStats extends string
+        ? WritableAccount<TAccountStats>
+        : TAccountStats,
       ...TRemainingAccounts,
     ]
   >;
@@ -73,17 +92,39 @@ export function getIncrementCounterInstructionDataCodec(): FixedSizeCodec<
   );
 }
 
-export type IncrementCounterInput<TAccountCounter extends string = string> = {
+export type IncrementCounterInput<
+  TAccountCounter extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+  TAccountStats extends string = string,
+> = {
   counter: Address<TAccountCounter>;
+  payer?: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  stats?: Address<TAccountStats>;
 };
 
 export function getIncrementCounterInstruction<
   TAccountCounter extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TAccountStats extends string,
   TProgramAddress extends Address = typeof COUNTER_PROGRAM_PROGRAM_ADDRESS,
 >(
-  input: IncrementCounterInput<TAccountCounter>,
+  input: IncrementCounterInput<
+    TAccountCounter,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountStats
+  >,
   config?: { programAddress?: TProgramAddress }
-): IncrementCounterInstruction<TProgramAddress, TAccountCounter> {
+): IncrementCounterInstruction<
+  TProgramAddress,
+  TAccountCounter,
+  TAccountPayer,
+  TAccountSystemProgram,
+  TAccountStats
+> {
   // Program address.
   const programAddress =
     config?.programAddress ?? COUNTER_PROGRAM_PROGRAM_ADDRESS;
@@ -91,18 +132,38 @@ export function getIncrementCounterInstruction<
   // Original accounts.
   const originalAccounts = {
     counter: { value: input.counter ?? null, isWritable: true },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    stats: { value: input.stats ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   return Object.freeze({
-    accounts: [getAccountMeta(accounts.counter)],
+    accounts: [
+      getAccountMeta(accounts.counter),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.stats),
+    ],
     data: getIncrementCounterInstructionDataEncoder().encode({}),
     programAddress,
-  } as IncrementCounterInstruction<TProgramAddress, TAccountCounter>);
+  } as IncrementCounterInstruction<
+    TProgramAddress,
+    TAccountCounter,
+    TAccountPayer,
+    TAccountSystemProgram,
+    TAccountStats
+  >);
 }
 
 export type ParsedIncrementCounterInstruction<
@@ -112,6 +173,9 @@ export type ParsedIncrementCounterInstruction<
   programAddress: Address<TProgram>;
   accounts: {
     counter: TAccountMetas[0];
+    payer: TAccountMetas[1] | undefined;
+    systemProgram: TAccountMetas[2] | undefined;
+    stats: TAccountMetas[3] | undefined;
   };
   data: IncrementCounterInstructionData;
 };
@@ -124,7 +188,7 @@ export function parseIncrementCounterInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedIncrementCounterInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 1) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -134,9 +198,20 @@ export function parseIncrementCounterInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === COUNTER_PROGRAM_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { counter: getNextAccount() },
+    accounts: {
+      counter: getNextAccount(),
+      payer: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+      stats: getNextOptionalAccount(),
+    },
     data: getIncrementCounterInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -6,6 +6,58 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adjustCounter';
+export * from './appendLog';
+export * from './assertCount';
+export * from './batchIncrement';
+export * from './claimAuthority';
+export * from './closeCounter';
+export * from './commitIncrement';
+export * from './compareAndIncrement';
+export * from './copyCounter';
+export * from './decrementCounter';
+export * from './decrementCounterCodamaClient';
+export * from './decrementCounterSaturating';
+export * from './getCount';
 export * from './incrementAnchorCounter';
+export * from './incrementAnchorCounterRaw';
+export * from './incrementAndTransfer';
+export * from './incrementAsDelegate';
+export * from './incrementBigCounter';
 export * from './incrementCounter';
+export * from './incrementCounterCodamaClient';
+export * from './incrementCounterMultisig';
+export * from './incrementCounterRateLimited';
+export * from './incrementCounterSaturating';
+export * from './incrementCounterSelfCpi';
+export * from './incrementCounterWithNonce';
+export * from './incrementHistoryCounter';
+export * from './incrementIfWhitelisted';
+export * from './incrementPdaCounterCodamaClient';
+export * from './initAndIncrementSelfCpi';
+export * from './initializeBigCounter';
 export * from './initializeCounter';
+export * from './initializeCounterCodamaClient';
+export * from './initializeCounterIdempotent';
+export * from './initializeCounterIndexed';
+export * from './initializeCounterPda';
+export * from './initializeCounterWithOverflowBehavior';
+export * from './initializeCounterWithSize';
+export * from './initializeCountersBatch';
+export * from './initializeHistoryCounter';
+export * from './initializeNamedCounter';
+export * from './initializeStats';
+export * from './initializeWhitelistCounter';
+export * from './logMemo';
+export * from './queryRent';
+export * from './recordEpoch';
+export * from './resetCounter';
+export * from './restoreCounter';
+export * from './revealIncrement';
+export * from './setDelegate';
+export * from './setMaxValue';
+export * from './setStep';
+export * from './snapshotCounter';
+export * from './toggleEnabled';
+export * from './transferAuthority';
+export * from './withdrawSurplus';
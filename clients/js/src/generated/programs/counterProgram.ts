@@ -13,9 +13,61 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAdjustCounterInstruction,
+  type ParsedAppendLogInstruction,
+  type ParsedAssertCountInstruction,
+  type ParsedBatchIncrementInstruction,
+  type ParsedClaimAuthorityInstruction,
+  type ParsedCloseCounterInstruction,
+  type ParsedCommitIncrementInstruction,
+  type ParsedCompareAndIncrementInstruction,
+  type ParsedCopyCounterInstruction,
+  type ParsedDecrementCounterCodamaClientInstruction,
+  type ParsedDecrementCounterInstruction,
+  type ParsedDecrementCounterSaturatingInstruction,
+  type ParsedGetCountInstruction,
   type ParsedIncrementAnchorCounterInstruction,
+  type ParsedIncrementAnchorCounterRawInstruction,
+  type ParsedIncrementAndTransferInstruction,
+  type ParsedIncrementAsDelegateInstruction,
+  type ParsedIncrementBigCounterInstruction,
+  type ParsedIncrementCounterCodamaClientInstruction,
   type ParsedIncrementCounterInstruction,
+  type ParsedIncrementCounterMultisigInstruction,
+  type ParsedIncrementCounterRateLimitedInstruction,
+  type ParsedIncrementCounterSaturatingInstruction,
+  type ParsedIncrementCounterSelfCpiInstruction,
+  type ParsedIncrementCounterWithNonceInstruction,
+  type ParsedIncrementHistoryCounterInstruction,
+  type ParsedIncrementIfWhitelistedInstruction,
+  type ParsedIncrementPdaCounterCodamaClientInstruction,
+  type ParsedInitAndIncrementSelfCpiInstruction,
+  type ParsedInitializeBigCounterInstruction,
+  type ParsedInitializeCounterCodamaClientInstruction,
+  type ParsedInitializeCounterIdempotentInstruction,
+  type ParsedInitializeCounterIndexedInstruction,
   type ParsedInitializeCounterInstruction,
+  type ParsedInitializeCounterPdaInstruction,
+  type ParsedInitializeCounterWithOverflowBehaviorInstruction,
+  type ParsedInitializeCounterWithSizeInstruction,
+  type ParsedInitializeCountersBatchInstruction,
+  type ParsedInitializeHistoryCounterInstruction,
+  type ParsedInitializeNamedCounterInstruction,
+  type ParsedInitializeStatsInstruction,
+  type ParsedInitializeWhitelistCounterInstruction,
+  type ParsedLogMemoInstruction,
+  type ParsedQueryRentInstruction,
+  type ParsedRecordEpochInstruction,
+  type ParsedResetCounterInstruction,
+  type ParsedRestoreCounterInstruction,
+  type ParsedRevealIncrementInstruction,
+  type ParsedSetDelegateInstruction,
+  type ParsedSetMaxValueInstruction,
+  type ParsedSetStepInstruction,
+  type ParsedSnapshotCounterInstruction,
+  type ParsedToggleEnabledInstruction,
+  type ParsedTransferAuthorityInstruction,
+  type ParsedWithdrawSurplusInstruction,
 } from '../instructions';
 
 export const COUNTER_PROGRAM_PROGRAM_ADDRESS =
@@ -23,12 +75,71 @@ export const COUNTER_PROGRAM_PROGRAM_ADDRESS =
 
 export enum CounterProgramAccount {
   CounterAccount,
+  BigCounterAccount,
+  HistoryCounterAccount,
+  NamedCounterAccount,
+  IndexedCounterAccount,
+  WhitelistCounterAccount,
+  CommitmentAccount,
+  StatsAccount,
 }
 
 export enum CounterProgramInstruction {
   InitializeCounter,
   IncrementCounter,
   IncrementAnchorCounter,
+  IncrementAnchorCounterRaw,
+  IncrementCounterSelfCpi,
+  IncrementCounterCodamaClient,
+  IncrementCounterMultisig,
+  SetStep,
+  DecrementCounterSaturating,
+  InitializeCounterCodamaClient,
+  InitializeCounterPda,
+  IncrementPdaCounterCodamaClient,
+  InitializeBigCounter,
+  IncrementBigCounter,
+  IncrementCounterRateLimited,
+  SetMaxValue,
+  DecrementCounter,
+  IncrementAndTransfer,
+  DecrementCounterCodamaClient,
+  InitializeHistoryCounter,
+  IncrementHistoryCounter,
+  ResetCounter,
+  TransferAuthority,
+  GetCount,
+  IncrementCounterSaturating,
+  ToggleEnabled,
+  InitializeNamedCounter,
+  InitializeCounterIndexed,
+  BatchIncrement,
+  SnapshotCounter,
+  RestoreCounter,
+  QueryRent,
+  LogMemo,
+  CompareAndIncrement,
+  CloseCounter,
+  InitializeCounterIdempotent,
+  SetDelegate,
+  IncrementAsDelegate,
+  WithdrawSurplus,
+  InitializeWhitelistCounter,
+  IncrementIfWhitelisted,
+  CommitIncrement,
+  RevealIncrement,
+  RecordEpoch,
+  InitializeCounterWithSize,
+  AppendLog,
+  InitializeCounterWithOverflowBehavior,
+  AdjustCounter,
+  InitAndIncrementSelfCpi,
+  InitializeStats,
+  InitializeCountersBatch,
+  IncrementCounterWithNonce,
+  CopyCounter,
+  AssertCount,
+  ClaimAuthority,
 }
 
 export function identifyCounterProgramInstruction(
@@ -44,6 +155,162 @@ export function identifyCounterProgramInstruction(
   if (containsBytes(data, getU8Encoder().encode(2), 0)) {
     return CounterProgramInstruction.IncrementAnchorCounter;
   }
+  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+    return CounterProgramInstruction.IncrementAnchorCounterRaw;
+  }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return CounterProgramInstruction.IncrementCounterSelfCpi;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return CounterProgramInstruction.IncrementCounterCodamaClient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return CounterProgramInstruction.IncrementCounterMultisig;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return CounterProgramInstruction.SetStep;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return CounterProgramInstruction.DecrementCounterSaturating;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return CounterProgramInstruction.InitializeCounterCodamaClient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return CounterProgramInstruction.InitializeCounterPda;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return CounterProgramInstruction.IncrementPdaCounterCodamaClient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return CounterProgramInstruction.InitializeBigCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return CounterProgramInstruction.IncrementBigCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return CounterProgramInstruction.IncrementCounterRateLimited;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return CounterProgramInstruction.SetMaxValue;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return CounterProgramInstruction.DecrementCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return CounterProgramInstruction.IncrementAndTransfer;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return CounterProgramInstruction.DecrementCounterCodamaClient;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return CounterProgramInstruction.InitializeHistoryCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return CounterProgramInstruction.IncrementHistoryCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return CounterProgramInstruction.ResetCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return CounterProgramInstruction.TransferAuthority;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return CounterProgramInstruction.GetCount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return CounterProgramInstruction.IncrementCounterSaturating;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return CounterProgramInstruction.ToggleEnabled;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return CounterProgramInstruction.InitializeNamedCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return CounterProgramInstruction.InitializeCounterIndexed;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return CounterProgramInstruction.BatchIncrement;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return CounterProgramInstruction.SnapshotCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return CounterProgramInstruction.RestoreCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return CounterProgramInstruction.QueryRent;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return CounterProgramInstruction.LogMemo;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return CounterProgramInstruction.CompareAndIncrement;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return CounterProgramInstruction.CloseCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return CounterProgramInstruction.InitializeCounterIdempotent;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return CounterProgramInstruction.SetDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return CounterProgramInstruction.IncrementAsDelegate;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return CounterProgramInstruction.WithdrawSurplus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return CounterProgramInstruction.InitializeWhitelistCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return CounterProgramInstruction.IncrementIfWhitelisted;
+  }
+  if (containsBytes(data, getU8Encoder().encode(41), 0)) {
+    return CounterProgramInstruction.CommitIncrement;
+  }
+  if (containsBytes(data, getU8Encoder().encode(42), 0)) {
+    return CounterProgramInstruction.RevealIncrement;
+  }
+  if (containsBytes(data, getU8Encoder().encode(43), 0)) {
+    return CounterProgramInstruction.RecordEpoch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(44), 0)) {
+    return CounterProgramInstruction.InitializeCounterWithSize;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return CounterProgramInstruction.AppendLog;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return CounterProgramInstruction.InitializeCounterWithOverflowBehavior;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return CounterProgramInstruction.AdjustCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return CounterProgramInstruction.InitAndIncrementSelfCpi;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return CounterProgramInstruction.InitializeStats;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return CounterProgramInstruction.InitializeCountersBatch;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return CounterProgramInstruction.IncrementCounterWithNonce;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return CounterProgramInstruction.CopyCounter;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return CounterProgramInstruction.AssertCount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return CounterProgramInstruction.ClaimAuthority;
+  }
   throw new Error(
     'The provided instruction could not be identified as a counterProgram instruction.'
   );
@@ -60,4 +327,160 @@ export type ParsedCounterProgramInstruction<
     } & ParsedIncrementCounterInstruction<TProgram>)
   | ({
       instructionType: CounterProgramInstruction.IncrementAnchorCounter;
-    } & ParsedIncrementAnchorCounterInstruction<TProgram>);
+    } & ParsedIncrementAnchorCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementAnchorCounterRaw;
+    } & ParsedIncrementAnchorCounterRawInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterSelfCpi;
+    } & ParsedIncrementCounterSelfCpiInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterCodamaClient;
+    } & ParsedIncrementCounterCodamaClientInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterMultisig;
+    } & ParsedIncrementCounterMultisigInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.SetStep;
+    } & ParsedSetStepInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.DecrementCounterSaturating;
+    } & ParsedDecrementCounterSaturatingInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterCodamaClient;
+    } & ParsedInitializeCounterCodamaClientInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterPda;
+    } & ParsedInitializeCounterPdaInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementPdaCounterCodamaClient;
+    } & ParsedIncrementPdaCounterCodamaClientInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeBigCounter;
+    } & ParsedInitializeBigCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementBigCounter;
+    } & ParsedIncrementBigCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterRateLimited;
+    } & ParsedIncrementCounterRateLimitedInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.SetMaxValue;
+    } & ParsedSetMaxValueInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.DecrementCounter;
+    } & ParsedDecrementCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementAndTransfer;
+    } & ParsedIncrementAndTransferInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.DecrementCounterCodamaClient;
+    } & ParsedDecrementCounterCodamaClientInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeHistoryCounter;
+    } & ParsedInitializeHistoryCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementHistoryCounter;
+    } & ParsedIncrementHistoryCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.ResetCounter;
+    } & ParsedResetCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.TransferAuthority;
+    } & ParsedTransferAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.GetCount;
+    } & ParsedGetCountInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterSaturating;
+    } & ParsedIncrementCounterSaturatingInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.ToggleEnabled;
+    } & ParsedToggleEnabledInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeNamedCounter;
+    } & ParsedInitializeNamedCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterIndexed;
+    } & ParsedInitializeCounterIndexedInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.BatchIncrement;
+    } & ParsedBatchIncrementInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.SnapshotCounter;
+    } & ParsedSnapshotCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.RestoreCounter;
+    } & ParsedRestoreCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.QueryRent;
+    } & ParsedQueryRentInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.LogMemo;
+    } & ParsedLogMemoInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.CompareAndIncrement;
+    } & ParsedCompareAndIncrementInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.CloseCounter;
+    } & ParsedCloseCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterIdempotent;
+    } & ParsedInitializeCounterIdempotentInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.SetDelegate;
+    } & ParsedSetDelegateInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementAsDelegate;
+    } & ParsedIncrementAsDelegateInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.WithdrawSurplus;
+    } & ParsedWithdrawSurplusInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeWhitelistCounter;
+    } & ParsedInitializeWhitelistCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementIfWhitelisted;
+    } & ParsedIncrementIfWhitelistedInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.CommitIncrement;
+    } & ParsedCommitIncrementInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.RevealIncrement;
+    } & ParsedRevealIncrementInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.RecordEpoch;
+    } & ParsedRecordEpochInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterWithSize;
+    } & ParsedInitializeCounterWithSizeInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.AppendLog;
+    } & ParsedAppendLogInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCounterWithOverflowBehavior;
+    } & ParsedInitializeCounterWithOverflowBehaviorInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.AdjustCounter;
+    } & ParsedAdjustCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitAndIncrementSelfCpi;
+    } & ParsedInitAndIncrementSelfCpiInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeStats;
+    } & ParsedInitializeStatsInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.InitializeCountersBatch;
+    } & ParsedInitializeCountersBatchInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.IncrementCounterWithNonce;
+    } & ParsedIncrementCounterWithNonceInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.CopyCounter;
+    } & ParsedCopyCounterInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.AssertCount;
+    } & ParsedAssertCountInstruction<TProgram>)
+  | ({
+      instructionType: CounterProgramInstruction.ClaimAuthority;
+    } & ParsedClaimAuthorityInstruction<TProgram>);
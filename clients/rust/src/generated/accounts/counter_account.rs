@@ -7,12 +7,25 @@
 
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
+use solana_pubkey::Pubkey;
 
 
 #[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CounterAccount {
 pub count: u64,
+pub step: u64,
+pub last_updated: i64,
+pub max_value: u64,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub authority: Pubkey,
+pub enabled: bool,
+pub snapshot: u64,
+/// Tag (`true` when set) and key, zeroed when unset: the program's `Option<Pubkey>` at a fixed size
+pub delegate: (bool, Pubkey),
+pub last_epoch: u64,
+pub overflow_behavior: u8,
+pub nonce: u64,
 }
 
 
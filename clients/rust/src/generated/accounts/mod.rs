@@ -5,7 +5,21 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#big_counter_account;
+  pub(crate) mod r#commitment_account;
   pub(crate) mod r#counter_account;
+  pub(crate) mod r#history_counter_account;
+  pub(crate) mod r#indexed_counter_account;
+  pub(crate) mod r#named_counter_account;
+  pub(crate) mod r#stats_account;
+  pub(crate) mod r#whitelist_counter_account;
 
+  pub use self::r#big_counter_account::*;
+  pub use self::r#commitment_account::*;
   pub use self::r#counter_account::*;
+  pub use self::r#history_counter_account::*;
+  pub use self::r#indexed_counter_account::*;
+  pub use self::r#named_counter_account::*;
+  pub use self::r#stats_account::*;
+  pub use self::r#whitelist_counter_account::*;
 
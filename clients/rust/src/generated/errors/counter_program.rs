@@ -19,6 +19,51 @@ pub enum CounterProgramError {
     /// 2 - Incorrect program ID
     #[error("Incorrect program ID")]
     IncorrectProgramId = 0x2,
+    /// 3 - Counter account is already initialized
+    #[error("Counter account is already initialized")]
+    AlreadyInitialized = 0x3,
+    /// 4 - Counter was updated too recently
+    #[error("Counter was updated too recently")]
+    RateLimited = 0x4,
+    /// 5 - Counter underflow occurred
+    #[error("Counter underflow occurred")]
+    CounterUnderflow = 0x5,
+    /// 6 - More accounts than the instruction takes
+    #[error("More accounts than the instruction takes")]
+    UnexpectedAccountCount = 0x6,
+    /// 7 - Signer is not the counter authority
+    #[error("Signer is not the counter authority")]
+    InvalidAuthority = 0x7,
+    /// 8 - Counter account and payer must be different accounts
+    #[error("Counter account and payer must be different accounts")]
+    CounterIsPayer = 0x8,
+    /// 9 - Counter is disabled
+    #[error("Counter is disabled")]
+    CounterDisabled = 0x9,
+    /// 10 - Program account is not executable
+    #[error("Program account is not executable")]
+    ProgramNotExecutable = 0xa,
+    /// 11 - Instruction requires a feature this program was built without
+    #[error("Instruction requires a feature this program was built without")]
+    FeatureDisabled = 0xb,
+    /// 12 - Counter does not hold the expected value
+    #[error("Counter does not hold the expected value")]
+    CasMismatch = 0xc,
+    /// 13 - Merkle proof does not match the whitelist root
+    #[error("Merkle proof does not match the whitelist root")]
+    InvalidMerkleProof = 0xd,
+    /// 14 - No pending commitment to reveal
+    #[error("No pending commitment to reveal")]
+    NoCommitment = 0xe,
+    /// 15 - Revealed nonce does not match the commitment
+    #[error("Revealed nonce does not match the commitment")]
+    RevealMismatch = 0xf,
+    /// 16 - A required account is missing
+    #[error("A required account is missing")]
+    MissingAccount = 0x10,
+    /// 17 - Nonce is not above the last one accepted
+    #[error("Nonce is not above the last one accepted")]
+    StaleNonce = 0x11,
 }
 
 impl From<CounterProgramError> for solana_program_error::ProgramError {
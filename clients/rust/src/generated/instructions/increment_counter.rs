@@ -16,6 +16,15 @@ pub struct IncrementCounter {
       
               
           pub counter: solana_pubkey::Pubkey,
+          
+              
+          pub payer: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub system_program: Option<solana_pubkey::Pubkey>,
+          
+              
+          pub stats: Option<solana_pubkey::Pubkey>,
       }
 
 impl IncrementCounter {
@@ -25,11 +34,44 @@ impl IncrementCounter {
   #[allow(clippy::arithmetic_side_effects)]
   #[allow(clippy::vec_init_then_push)]
   pub fn instruction_with_remaining_accounts(&self, remaining_accounts: &[solana_instruction::AccountMeta]) -> solana_instruction::Instruction {
-    let mut accounts = Vec::with_capacity(1+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(4+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             self.counter,
             false
           ));
+                                          if let Some(payer) = self.payer {
+              accounts.push(solana_instruction::AccountMeta::new(
+                payer,
+                true
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
+                                          if let Some(system_program) = self.system_program {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                system_program,
+                false
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
+                                          if let Some(stats) = self.stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                stats,
+                false
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
                       accounts.extend_from_slice(remaining_accounts);
     let data = IncrementCounterInstructionData::new().try_to_vec().unwrap();
     
@@ -72,9 +114,15 @@ impl Default for IncrementCounterInstructionData {
 /// ### Accounts:
 ///
                 ///   0. `[writable]` counter
+                            ///   1. `[writable, signer, optional]` payer
+                ///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                      ///   3. `[writable, optional]` stats
 #[derive(Clone, Debug, Default)]
 pub struct IncrementCounterBuilder {
             counter: Option<solana_pubkey::Pubkey>,
+                payer: Option<solana_pubkey::Pubkey>,
+                system_program: Option<solana_pubkey::Pubkey>,
+                stats: Option<solana_pubkey::Pubkey>,
                 __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -86,6 +134,24 @@ impl IncrementCounterBuilder {
     pub fn counter(&mut self, counter: solana_pubkey::Pubkey) -> &mut Self {
                         self.counter = Some(counter);
                     self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn payer(&mut self, payer: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.payer = payer;
+                    self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.system_program = system_program;
+                    self
+    }
+            /// `[optional account]`
+#[inline(always)]
+    pub fn stats(&mut self, stats: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.stats = stats;
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -103,6 +169,9 @@ impl IncrementCounterBuilder {
   pub fn instruction(&self) -> solana_instruction::Instruction {
     let accounts = IncrementCounter {
                               counter: self.counter.expect("counter is not set"),
+                                        payer: self.payer,
+                                        system_program: self.system_program,
+                                        stats: self.stats,
                       };
     
     accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -114,6 +183,15 @@ impl IncrementCounterBuilder {
           
                     
               pub counter: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
+              pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                
+                    
+              pub stats: Option<&'b solana_account_info::AccountInfo<'a>>,
             }
 
 /// `increment_counter` CPI instruction.
@@ -123,6 +201,15 @@ pub struct IncrementCounterCpi<'a, 'b> {
       
               
           pub counter: &'b solana_account_info::AccountInfo<'a>,
+          
+              
+          pub payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+          
+              
+          pub stats: Option<&'b solana_account_info::AccountInfo<'a>>,
         }
 
 impl<'a, 'b> IncrementCounterCpi<'a, 'b> {
@@ -133,6 +220,9 @@ impl<'a, 'b> IncrementCounterCpi<'a, 'b> {
     Self {
       __program: program,
               counter: accounts.counter,
+              payer: accounts.payer,
+              system_program: accounts.system_program,
+              stats: accounts.stats,
                 }
   }
   #[inline(always)]
@@ -155,11 +245,44 @@ impl<'a, 'b> IncrementCounterCpi<'a, 'b> {
     signers_seeds: &[&[&[u8]]],
     remaining_accounts: &[(&'b solana_account_info::AccountInfo<'a>, bool, bool)]
   ) -> solana_program_error::ProgramResult {
-    let mut accounts = Vec::with_capacity(1+ remaining_accounts.len());
+    let mut accounts = Vec::with_capacity(4+ remaining_accounts.len());
                             accounts.push(solana_instruction::AccountMeta::new(
             *self.counter.key,
             false
           ));
+                                          if let Some(payer) = self.payer {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *payer.key,
+                true
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
+                                          if let Some(system_program) = self.system_program {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *system_program.key,
+                false
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
+                                          if let Some(stats) = self.stats {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *stats.key,
+                false
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::COUNTER_PROGRAM_ID,
+                false,
+              ));
+            }
                       remaining_accounts.iter().for_each(|remaining_account| {
       accounts.push(solana_instruction::AccountMeta {
           pubkey: *remaining_account.0.key,
@@ -174,9 +297,18 @@ impl<'a, 'b> IncrementCounterCpi<'a, 'b> {
       accounts,
       data,
     };
-    let mut account_infos = Vec::with_capacity(2 + remaining_accounts.len());
+    let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
     account_infos.push(self.__program.clone());
                   account_infos.push(self.counter.clone());
+                        if let Some(payer) = self.payer {
+                account_infos.push(payer.clone());
+              }
+                        if let Some(system_program) = self.system_program {
+                account_infos.push(system_program.clone());
+              }
+                        if let Some(stats) = self.stats {
+                account_infos.push(stats.clone());
+              }
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
     if signers_seeds.is_empty() {
@@ -192,6 +324,9 @@ impl<'a, 'b> IncrementCounterCpi<'a, 'b> {
 /// ### Accounts:
 ///
                 ///   0. `[writable]` counter
+                            ///   1. `[writable, signer, optional]` payer
+                ///   2. `[optional]` system_program
+                      ///   3. `[writable, optional]` stats
 #[derive(Clone, Debug)]
 pub struct IncrementCounterCpiBuilder<'a, 'b> {
   instruction: Box<IncrementCounterCpiBuilderInstruction<'a, 'b>>,
@@ -202,6 +337,9 @@ impl<'a, 'b> IncrementCounterCpiBuilder<'a, 'b> {
     let instruction = Box::new(IncrementCounterCpiBuilderInstruction {
       __program: program,
               counter: None,
+              payer: None,
+              system_program: None,
+              stats: None,
                                 __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -210,6 +348,24 @@ impl<'a, 'b> IncrementCounterCpiBuilder<'a, 'b> {
     pub fn counter(&mut self, counter: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.counter = Some(counter);
                     self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn payer(&mut self, payer: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.payer = payer;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn system_program(&mut self, system_program: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.system_program = system_program;
+                    self
+    }
+      /// `[optional account]`
+#[inline(always)]
+    pub fn stats(&mut self, stats: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.stats = stats;
+                    self
     }
             /// Add an additional account to the instruction.
   #[inline(always)]
@@ -237,6 +393,12 @@ impl<'a, 'b> IncrementCounterCpiBuilder<'a, 'b> {
         __program: self.instruction.__program,
                   
           counter: self.instruction.counter.expect("counter is not set"),
+                  
+          payer: self.instruction.payer,
+                  
+          system_program: self.instruction.system_program,
+                  
+          stats: self.instruction.stats,
                     };
     instruction.invoke_signed_with_remaining_accounts(signers_seeds, &self.instruction.__remaining_accounts)
   }
@@ -246,6 +408,9 @@ impl<'a, 'b> IncrementCounterCpiBuilder<'a, 'b> {
 struct IncrementCounterCpiBuilderInstruction<'a, 'b> {
   __program: &'b solana_account_info::AccountInfo<'a>,
             counter: Option<&'b solana_account_info::AccountInfo<'a>>,
+                payer: Option<&'b solana_account_info::AccountInfo<'a>>,
+                system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
+                stats: Option<&'b solana_account_info::AccountInfo<'a>>,
                 /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -5,11 +5,115 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+  pub(crate) mod r#adjust_counter;
+  pub(crate) mod r#append_log;
+  pub(crate) mod r#assert_count;
+  pub(crate) mod r#batch_increment;
+  pub(crate) mod r#claim_authority;
+  pub(crate) mod r#close_counter;
+  pub(crate) mod r#commit_increment;
+  pub(crate) mod r#compare_and_increment;
+  pub(crate) mod r#copy_counter;
+  pub(crate) mod r#decrement_counter;
+  pub(crate) mod r#decrement_counter_codama_client;
+  pub(crate) mod r#decrement_counter_saturating;
+  pub(crate) mod r#get_count;
   pub(crate) mod r#increment_anchor_counter;
+  pub(crate) mod r#increment_anchor_counter_raw;
+  pub(crate) mod r#increment_and_transfer;
+  pub(crate) mod r#increment_as_delegate;
+  pub(crate) mod r#increment_big_counter;
   pub(crate) mod r#increment_counter;
+  pub(crate) mod r#increment_counter_codama_client;
+  pub(crate) mod r#increment_counter_multisig;
+  pub(crate) mod r#increment_counter_rate_limited;
+  pub(crate) mod r#increment_counter_saturating;
+  pub(crate) mod r#increment_counter_self_cpi;
+  pub(crate) mod r#increment_counter_with_nonce;
+  pub(crate) mod r#increment_history_counter;
+  pub(crate) mod r#increment_if_whitelisted;
+  pub(crate) mod r#increment_pda_counter_codama_client;
+  pub(crate) mod r#init_and_increment_self_cpi;
+  pub(crate) mod r#initialize_big_counter;
   pub(crate) mod r#initialize_counter;
+  pub(crate) mod r#initialize_counter_codama_client;
+  pub(crate) mod r#initialize_counter_idempotent;
+  pub(crate) mod r#initialize_counter_indexed;
+  pub(crate) mod r#initialize_counter_pda;
+  pub(crate) mod r#initialize_counter_with_overflow_behavior;
+  pub(crate) mod r#initialize_counter_with_size;
+  pub(crate) mod r#initialize_counters_batch;
+  pub(crate) mod r#initialize_history_counter;
+  pub(crate) mod r#initialize_named_counter;
+  pub(crate) mod r#initialize_stats;
+  pub(crate) mod r#initialize_whitelist_counter;
+  pub(crate) mod r#log_memo;
+  pub(crate) mod r#query_rent;
+  pub(crate) mod r#record_epoch;
+  pub(crate) mod r#reset_counter;
+  pub(crate) mod r#restore_counter;
+  pub(crate) mod r#reveal_increment;
+  pub(crate) mod r#set_delegate;
+  pub(crate) mod r#set_max_value;
+  pub(crate) mod r#set_step;
+  pub(crate) mod r#snapshot_counter;
+  pub(crate) mod r#toggle_enabled;
+  pub(crate) mod r#transfer_authority;
+  pub(crate) mod r#withdraw_surplus;
 
+  pub use self::r#adjust_counter::*;
+  pub use self::r#append_log::*;
+  pub use self::r#assert_count::*;
+  pub use self::r#batch_increment::*;
+  pub use self::r#claim_authority::*;
+  pub use self::r#close_counter::*;
+  pub use self::r#commit_increment::*;
+  pub use self::r#compare_and_increment::*;
+  pub use self::r#copy_counter::*;
+  pub use self::r#decrement_counter::*;
+  pub use self::r#decrement_counter_codama_client::*;
+  pub use self::r#decrement_counter_saturating::*;
+  pub use self::r#get_count::*;
   pub use self::r#increment_anchor_counter::*;
+  pub use self::r#increment_anchor_counter_raw::*;
+  pub use self::r#increment_and_transfer::*;
+  pub use self::r#increment_as_delegate::*;
+  pub use self::r#increment_big_counter::*;
   pub use self::r#increment_counter::*;
+  pub use self::r#increment_counter_codama_client::*;
+  pub use self::r#increment_counter_multisig::*;
+  pub use self::r#increment_counter_rate_limited::*;
+  pub use self::r#increment_counter_saturating::*;
+  pub use self::r#increment_counter_self_cpi::*;
+  pub use self::r#increment_counter_with_nonce::*;
+  pub use self::r#increment_history_counter::*;
+  pub use self::r#increment_if_whitelisted::*;
+  pub use self::r#increment_pda_counter_codama_client::*;
+  pub use self::r#init_and_increment_self_cpi::*;
+  pub use self::r#initialize_big_counter::*;
   pub use self::r#initialize_counter::*;
+  pub use self::r#initialize_counter_codama_client::*;
+  pub use self::r#initialize_counter_idempotent::*;
+  pub use self::r#initialize_counter_indexed::*;
+  pub use self::r#initialize_counter_pda::*;
+  pub use self::r#initialize_counter_with_overflow_behavior::*;
+  pub use self::r#initialize_counter_with_size::*;
+  pub use self::r#initialize_counters_batch::*;
+  pub use self::r#initialize_history_counter::*;
+  pub use self::r#initialize_named_counter::*;
+  pub use self::r#initialize_stats::*;
+  pub use self::r#initialize_whitelist_counter::*;
+  pub use self::r#log_memo::*;
+  pub use self::r#query_rent::*;
+  pub use self::r#record_epoch::*;
+  pub use self::r#reset_counter::*;
+  pub use self::r#restore_counter::*;
+  pub use self::r#reveal_increment::*;
+  pub use self::r#set_delegate::*;
+  pub use self::r#set_max_value::*;
+  pub use self::r#set_step::*;
+  pub use self::r#snapshot_counter::*;
+  pub use self::r#toggle_enabled::*;
+  pub use self::r#transfer_authority::*;
+  pub use self::r#withdraw_surplus::*;
 
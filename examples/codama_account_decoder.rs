@@ -0,0 +1,111 @@
+// Decode a counter account with the Codama-generated account type
+//
+// `codama_offchain` builds instructions with the generated client; this example
+// covers the other direction, reading state back. The counter is fetched from
+// LiteSVM and decoded twice: with `codama_client::accounts::CounterAccount`
+// (generated from the IDL) and with the program's own `CounterAccount` (borsh).
+// The generated type covers the whole account, so both must agree on every field.
+//
+// Build the program first with `cargo build-sbf`, then run:
+//   cargo run --example codama_account_decoder
+use counter_program::{CounterAccount, CounterInstruction};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::ReadableAccount,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+fn send(svm: &mut LiteSVM, payer: &Keypair, signers: &[&Keypair], instruction: Instruction) {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&all_signers, message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Transaction failed");
+}
+
+fn main() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    let program_id = Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+    svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
+        .expect("Build the program with `cargo build-sbf` first");
+
+    // ===== Create a counter at 7 and increment it twice =====
+    let counter_keypair = Keypair::new();
+    let data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 7 }).unwrap();
+    let initialize_instruction = Instruction::new_with_bytes(
+        program_id,
+        &data,
+        vec![
+            AccountMeta::new(counter_keypair.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    );
+    send(
+        &mut svm,
+        &payer,
+        &[&counter_keypair],
+        initialize_instruction,
+    );
+
+    let data = borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap();
+    for _ in 0..2 {
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        send(&mut svm, &payer, &[], increment_instruction);
+        svm.expire_blockhash();
+    }
+
+    // ===== Fetch and decode =====
+    let account = svm
+        .get_account(&counter_keypair.pubkey())
+        .expect("Counter account should exist");
+    println!(
+        "Counter {} ({} bytes, owner {})",
+        counter_keypair.pubkey(),
+        account.data().len(),
+        account.owner()
+    );
+
+    let generated = codama_client::accounts::CounterAccount::from_bytes(account.data())
+        .expect("Failed to decode with the Codama account type");
+    println!("Codama-generated decoder: {:?}", generated);
+
+    let native = CounterAccount::load(account.data()).expect("Failed to decode with borsh");
+    println!("Program's borsh decoder:  {:?}", native);
+
+    assert_eq!(generated.count, native.count);
+    assert_eq!(generated.step, native.step);
+    assert_eq!(generated.last_updated, native.last_updated);
+    assert_eq!(generated.max_value, native.max_value);
+    assert_eq!(generated.authority.to_bytes(), native.authority.to_bytes());
+    assert_eq!(generated.enabled, native.enabled);
+    assert_eq!(generated.snapshot, native.snapshot);
+    // The generated delegate is the on-chain tag and key, zeroed when unset
+    assert_eq!(generated.delegate.0, native.delegate.is_some());
+    assert_eq!(
+        generated.delegate.1.to_bytes(),
+        native.delegate.unwrap_or_default().to_bytes()
+    );
+    assert_eq!(generated.last_epoch, native.last_epoch);
+    assert_eq!(generated.overflow_behavior, native.overflow_behavior);
+    assert_eq!(generated.nonce, native.nonce);
+    assert_eq!(native.count, 9);
+    println!(
+        "\nBoth decoders agree on every field: count = {}",
+        generated.count
+    );
+}
@@ -0,0 +1,102 @@
+// Off-chain Rust client using the Codama-generated instruction builders
+//
+// The CPI builders are exercised by the program itself; this example shows the
+// other half of the generated client: building plain instructions off-chain and
+// sending them in a transaction (here against LiteSVM instead of a validator).
+//
+// Build the program first with `cargo build-sbf`, then run:
+//   cargo run --example codama_offchain
+use codama_client::instructions::{IncrementCounterBuilder, InitializeCounterBuilder};
+use counter_program::CounterAccount;
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::ReadableAccount,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+// The Codama client uses the solana 2.x instruction types, while LiteSVM expects
+// the solana-sdk ones, so rebuild the instruction field by field
+fn to_sdk_instruction(instruction: solana_instruction::Instruction) -> Instruction {
+    Instruction {
+        program_id: Pubkey::new_from_array(instruction.program_id.to_bytes()),
+        accounts: instruction
+            .accounts
+            .into_iter()
+            .map(|meta| AccountMeta {
+                pubkey: Pubkey::new_from_array(meta.pubkey.to_bytes()),
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: instruction.data,
+    }
+}
+
+fn to_client_pubkey(pubkey: &Pubkey) -> solana_pubkey::Pubkey {
+    solana_pubkey::Pubkey::new_from_array(pubkey.to_bytes())
+}
+
+fn main() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+    // The generated builders target the program ID from the IDL,
+    // so deploy the program at that address
+    let program_id = Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+    svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
+        .expect("Build the program with `cargo build-sbf` first");
+
+    println!("Initializing counter with InitializeCounterBuilder...");
+    let counter_keypair = Keypair::new();
+    let initial_value = 100u64;
+
+    // system_program is filled in automatically by the builder
+    let initialize_instruction = InitializeCounterBuilder::new()
+        .counter(to_client_pubkey(&counter_keypair.pubkey()))
+        .payer(to_client_pubkey(&payer.pubkey()))
+        .initial_value(initial_value)
+        .instruction();
+
+    let message = Message::new(
+        &[to_sdk_instruction(initialize_instruction)],
+        Some(&payer.pubkey()),
+    );
+    let transaction =
+        Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Failed to initialize counter");
+    println!("Counter initialized: {}", counter_keypair.pubkey());
+
+    println!("\nIncrementing counter with IncrementCounterBuilder...");
+    let increment_instruction = IncrementCounterBuilder::new()
+        .counter(to_client_pubkey(&counter_keypair.pubkey()))
+        .instruction();
+
+    let message = Message::new(
+        &[to_sdk_instruction(increment_instruction)],
+        Some(&payer.pubkey()),
+    );
+    let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Failed to increment counter");
+
+    let account = svm
+        .get_account(&counter_keypair.pubkey())
+        .expect("Counter account should exist");
+    let counter = CounterAccount::load(account.data()).expect("Failed to decode counter");
+
+    assert_eq!(
+        counter.count,
+        initial_value + 1,
+        "Counter should be incremented via the Codama builder"
+    );
+    println!(
+        "Counter incremented: {} -> {}",
+        initial_value, counter.count
+    );
+}
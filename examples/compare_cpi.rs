@@ -0,0 +1,205 @@
+// Side-by-side comparison of the four CPI approaches in this repo
+//
+// Each path runs against a freshly initialized counter and prints whether it
+// succeeded, the compute units it used, and its program logs:
+//   - IncrementAnchorCounter:       Anchor-generated CPI client
+//   - IncrementAnchorCounterRaw:    hand-built Anchor discriminator
+//   - IncrementCounterSelfCpi:      hand-built native instruction (self-CPI)
+//   - IncrementCounterCodamaClient: Codama-generated CPI client (self-CPI)
+//
+// Build both programs first (`cargo build-sbf` and `anchor build` in
+// anchor-counter/), then run:
+//   cargo run --example compare_cpi
+use counter_program::{AnchorIx, CounterInstruction};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+struct CpiResult {
+    name: &'static str,
+    succeeded: bool,
+    compute_units: u64,
+    logs: Vec<String>,
+}
+
+fn send(svm: &mut LiteSVM, payer: &Keypair, signers: &[&Keypair], instruction: Instruction) {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&all_signers, message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Setup transaction failed");
+}
+
+fn init_native_counter(svm: &mut LiteSVM, payer: &Keypair, program_id: Pubkey) -> Keypair {
+    let counter = Keypair::new();
+    let data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 }).unwrap();
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &data,
+        vec![
+            AccountMeta::new(counter.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    );
+    send(svm, payer, &[&counter], instruction);
+    counter
+}
+
+fn init_anchor_counter(svm: &mut LiteSVM, payer: &Keypair, anchor_program_id: Pubkey) -> Keypair {
+    let counter = Keypair::new();
+    let instruction = Instruction::new_with_bytes(
+        anchor_program_id,
+        &AnchorIx::InitializeCounter { initial_value: 0 }.to_data(),
+        vec![
+            AccountMeta::new(counter.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    );
+    send(svm, payer, &[&counter], instruction);
+    counter
+}
+
+fn run(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    name: &'static str,
+    instruction: Instruction,
+) -> CpiResult {
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+
+    match svm.send_transaction(transaction) {
+        Ok(meta) => CpiResult {
+            name,
+            succeeded: true,
+            compute_units: meta.compute_units_consumed,
+            logs: meta.logs,
+        },
+        Err(failed) => CpiResult {
+            name,
+            succeeded: false,
+            compute_units: failed.meta.compute_units_consumed,
+            logs: failed.meta.logs,
+        },
+    }
+}
+
+fn main() {
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 2_000_000_000).unwrap();
+
+    // The Codama CPI builder targets the program ID from the IDL,
+    // so deploy the native program at that address
+    let native_program_id =
+        Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+    svm.add_program_from_file(native_program_id, "target/deploy/counter_program.so")
+        .expect("Build the native program with `cargo build-sbf` first");
+
+    let anchor_program_id =
+        read_keypair_file("anchor-counter/target/deploy/anchor_counter-keypair.json")
+            .expect("Build the Anchor program with `anchor build` first")
+            .pubkey();
+    svm.add_program_from_file(
+        anchor_program_id,
+        "anchor-counter/target/deploy/anchor_counter.so",
+    )
+    .expect("Build the Anchor program with `anchor build` first");
+
+    let mut results = Vec::new();
+
+    // Native -> Anchor, Anchor-generated CPI client
+    let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id);
+    let instruction = Instruction::new_with_bytes(
+        native_program_id,
+        &borsh::to_vec(&CounterInstruction::IncrementAnchorCounter).unwrap(),
+        vec![
+            AccountMeta::new(anchor_counter.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+            AccountMeta::new_readonly(anchor_program_id, false),
+        ],
+    );
+    results.push(run(&mut svm, &payer, "IncrementAnchorCounter", instruction));
+
+    // Native -> Anchor, manual discriminator
+    let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id);
+    let instruction = Instruction::new_with_bytes(
+        native_program_id,
+        &borsh::to_vec(&CounterInstruction::IncrementAnchorCounterRaw).unwrap(),
+        vec![
+            AccountMeta::new(anchor_counter.pubkey(), false),
+            AccountMeta::new_readonly(payer.pubkey(), true),
+            AccountMeta::new_readonly(anchor_program_id, false),
+        ],
+    );
+    results.push(run(
+        &mut svm,
+        &payer,
+        "IncrementAnchorCounterRaw",
+        instruction,
+    ));
+
+    // Native -> Native, manual instruction
+    let native_counter = init_native_counter(&mut svm, &payer, native_program_id);
+    let instruction = Instruction::new_with_bytes(
+        native_program_id,
+        &borsh::to_vec(&CounterInstruction::IncrementCounterSelfCpi).unwrap(),
+        vec![
+            AccountMeta::new(native_counter.pubkey(), false),
+            AccountMeta::new_readonly(native_program_id, false),
+        ],
+    );
+    results.push(run(
+        &mut svm,
+        &payer,
+        "IncrementCounterSelfCpi",
+        instruction,
+    ));
+
+    // Native -> Native, Codama-generated CPI client
+    let native_counter = init_native_counter(&mut svm, &payer, native_program_id);
+    let instruction = Instruction::new_with_bytes(
+        native_program_id,
+        &borsh::to_vec(&CounterInstruction::IncrementCounterCodamaClient).unwrap(),
+        vec![
+            AccountMeta::new(native_counter.pubkey(), false),
+            AccountMeta::new_readonly(native_program_id, false),
+        ],
+    );
+    results.push(run(
+        &mut svm,
+        &payer,
+        "IncrementCounterCodamaClient",
+        instruction,
+    ));
+
+    for result in &results {
+        println!("\n=== {} ===", result.name);
+        for log in &result.logs {
+            println!("  {}", log);
+        }
+    }
+
+    println!(
+        "\n{:<30} {:<8} {:>14}",
+        "CPI path", "Result", "Compute units"
+    );
+    for result in &results {
+        println!(
+            "{:<30} {:<8} {:>14}",
+            result.name,
+            if result.succeeded { "ok" } else { "failed" },
+            result.compute_units
+        );
+    }
+}
@@ -0,0 +1,74 @@
+// Off-chain timing of borsh versus the hand-written `Pack` layout for `CounterAccount`
+//
+// Both produce the same bytes (see the `Pack` impl in src/state.rs); this measures
+// what each costs to encode and decode on the host. Wall-clock numbers only say
+// which is cheaper relative to the other, not what either costs on-chain, so
+// build in release mode:
+//   cargo run --release --example serde_bench
+use borsh::BorshDeserialize;
+use counter_program::CounterAccount;
+use solana_program::{program_pack::Pack, pubkey::Pubkey};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 1_000_000;
+
+fn sample_counter() -> CounterAccount {
+    CounterAccount {
+        count: 42,
+        step: 3,
+        last_updated: 1_700_000_000,
+        max_value: 1_000,
+        authority: Pubkey::new_from_array([7; 32]),
+        enabled: true,
+        snapshot: 40,
+        delegate: Some(Pubkey::new_from_array([9; 32])),
+        last_epoch: 5,
+        overflow_behavior: 1,
+        nonce: 11,
+    }
+}
+
+// Run `f` ITERATIONS times and return the average time per call
+fn time_per_call(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn main() {
+    let counter = sample_counter();
+
+    let borsh_bytes = borsh::to_vec(&counter).expect("Failed to serialize counter");
+    let mut packed = [0u8; CounterAccount::LEN];
+    counter.pack_into_slice(&mut packed);
+    assert_eq!(
+        borsh_bytes.as_slice(),
+        packed.as_slice(),
+        "Both encodings should produce the same bytes"
+    );
+
+    let borsh_encode = time_per_call(|| {
+        black_box(borsh::to_vec(black_box(&counter)).unwrap());
+    });
+    let borsh_decode = time_per_call(|| {
+        black_box(CounterAccount::try_from_slice(black_box(&borsh_bytes)).unwrap());
+    });
+    let pack_encode = time_per_call(|| {
+        black_box(&counter).pack_into_slice(black_box(&mut packed));
+    });
+    let pack_decode = time_per_call(|| {
+        black_box(CounterAccount::unpack_from_slice(black_box(&packed)).unwrap());
+    });
+
+    println!(
+        "CounterAccount ({} bytes), {} iterations each\n",
+        CounterAccount::LEN,
+        ITERATIONS
+    );
+    println!("{:<8} {:>12} {:>12}", "", "encode", "decode");
+    println!("{:<8} {:>12?} {:>12?}", "borsh", borsh_encode, borsh_decode);
+    println!("{:<8} {:>12?} {:>12?}", "pack", pack_encode, pack_decode);
+}
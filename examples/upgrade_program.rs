@@ -0,0 +1,248 @@
+// Upgrade the counter program in place and keep using a counter created before it
+//
+// Deploys the native program under the upgradeable BPF loader (v3) on LiteSVM,
+// initializes and increments a counter, then upgrades the program to a second
+// build with the loader's `Upgrade` instruction and increments the same counter
+// again. The program ID and the counter account never change, only the bytes
+// in the program data account do. This is what the self-CPI "old version calls
+// new version" use case relies on.
+//
+// Build the program first (`cargo build-sbf`), then run:
+//   cargo run --example upgrade_program [path/to/second_build.so]
+// Without an argument the same build is deployed twice.
+use counter_program::{CounterAccount, CounterInstruction};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::Account,
+    instruction::{AccountMeta, Instruction},
+    message::Message,
+    pubkey::Pubkey,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+const LOADER_V3_ID: Pubkey = solana_sdk::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+const RENT_SYSVAR_ID: Pubkey = solana_sdk::pubkey!("SysvarRent111111111111111111111111111111111");
+const CLOCK_SYSVAR_ID: Pubkey = solana_sdk::pubkey!("SysvarC1ock11111111111111111111111111111111");
+
+const PROGRAM_SO: &str = "target/deploy/counter_program.so";
+const PROGRAM_KEYPAIR: &str = "target/deploy/counter_program-keypair.json";
+
+// Loader v3 account states are bincode enums: a u32 tag, then the variant's fields
+const BUFFER_TAG: u32 = 1;
+const PROGRAM_TAG: u32 = 2;
+const PROGRAM_DATA_TAG: u32 = 3;
+
+// Bytes before the ELF: tag, Option<authority> (1 + 32)
+const BUFFER_METADATA_LEN: usize = 4 + 1 + 32;
+// Bytes before the ELF: tag, slot (u64), Option<upgrade authority> (1 + 32)
+const PROGRAM_DATA_METADATA_LEN: usize = 4 + 8 + 1 + 32;
+
+// `UpgradeableLoaderInstruction::Upgrade`
+const UPGRADE_INSTRUCTION: u32 = 3;
+
+fn buffer_data(authority: &Pubkey, elf: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(BUFFER_METADATA_LEN + elf.len());
+    data.extend_from_slice(&BUFFER_TAG.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(elf);
+    data
+}
+
+fn program_data(slot: u64, upgrade_authority: &Pubkey, elf: &[u8]) -> Vec<u8> {
+    let mut data = Vec::with_capacity(PROGRAM_DATA_METADATA_LEN + elf.len());
+    data.extend_from_slice(&PROGRAM_DATA_TAG.to_le_bytes());
+    data.extend_from_slice(&slot.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+    data.extend_from_slice(elf);
+    data
+}
+
+fn program_account_data(programdata_address: &Pubkey) -> Vec<u8> {
+    let mut data = PROGRAM_TAG.to_le_bytes().to_vec();
+    data.extend_from_slice(programdata_address.as_ref());
+    data
+}
+
+fn loader_account(svm: &LiteSVM, data: Vec<u8>, executable: bool) -> Account {
+    Account {
+        lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+        data,
+        owner: LOADER_V3_ID,
+        executable,
+        rent_epoch: 0,
+    }
+}
+
+// Deploy `elf` at `program_id` as an upgradeable program, as `solana program deploy` would
+//
+// Like `--max-len`, the program data gets room for an ELF of up to `max_len` bytes,
+// since an upgrade can't grow it.
+fn deploy_upgradeable(
+    svm: &mut LiteSVM,
+    program_id: Pubkey,
+    authority: &Pubkey,
+    elf: &[u8],
+    max_len: usize,
+) {
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &LOADER_V3_ID);
+
+    let mut data = program_data(0, authority, elf);
+    data.resize(PROGRAM_DATA_METADATA_LEN + max_len.max(elf.len()), 0);
+
+    // The program data must exist before the program account that points at it
+    let programdata = loader_account(svm, data, false);
+    svm.set_account(programdata_address, programdata)
+        .expect("Failed to set program data account");
+    let program = loader_account(svm, program_account_data(&programdata_address), true);
+    svm.set_account(program_id, program)
+        .expect("Failed to set program account");
+}
+
+// Replace the program's bytes with `elf` through the loader's `Upgrade` instruction
+fn upgrade(svm: &mut LiteSVM, program_id: Pubkey, authority: &Keypair, elf: &[u8]) {
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &LOADER_V3_ID);
+
+    let buffer = Pubkey::new_unique();
+    let buffer_account = loader_account(svm, buffer_data(&authority.pubkey(), elf), false);
+    svm.set_account(buffer, buffer_account)
+        .expect("Failed to set buffer account");
+
+    // The buffer's lamports are refunded to the spill account, here the authority
+    let instruction = Instruction::new_with_bytes(
+        LOADER_V3_ID,
+        &UPGRADE_INSTRUCTION.to_le_bytes(),
+        vec![
+            AccountMeta::new(programdata_address, false),
+            AccountMeta::new(program_id, false),
+            AccountMeta::new(buffer, false),
+            AccountMeta::new(authority.pubkey(), false),
+            AccountMeta::new_readonly(RENT_SYSVAR_ID, false),
+            AccountMeta::new_readonly(CLOCK_SYSVAR_ID, false),
+            AccountMeta::new_readonly(authority.pubkey(), true),
+        ],
+    );
+    send(svm, authority, &[], instruction).expect("Upgrade failed");
+}
+
+fn send(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    signers: &[&Keypair],
+    instruction: Instruction,
+) -> Result<(), String> {
+    let mut all_signers = vec![payer];
+    all_signers.extend_from_slice(signers);
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&all_signers, message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .map(|_| ())
+        .map_err(|failed| format!("{:?}\n{}", failed.err, failed.meta.logs.join("\n")))
+}
+
+fn increment(svm: &mut LiteSVM, payer: &Keypair, program_id: Pubkey, counter: Pubkey) -> u64 {
+    let data = borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap();
+    let instruction =
+        Instruction::new_with_bytes(program_id, &data, vec![AccountMeta::new(counter, false)]);
+    send(svm, payer, &[], instruction).expect("Increment failed");
+    svm.expire_blockhash();
+
+    let account = svm.get_account(&counter).expect("Counter account missing");
+    CounterAccount::load(&account.data)
+        .expect("Failed to decode counter")
+        .count
+}
+
+fn print_program_data(svm: &LiteSVM, program_id: Pubkey, label: &str) {
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[program_id.as_ref()], &LOADER_V3_ID);
+    let account = svm
+        .get_account(&programdata_address)
+        .expect("Program data account missing");
+    let data = &account.data;
+
+    let slot = u64::from_le_bytes(data[4..12].try_into().unwrap());
+    let authority = match data[12] {
+        1 => Pubkey::try_from(&data[13..45]).unwrap().to_string(),
+        _ => "none (immutable)".to_string(),
+    };
+    let elf = &data[PROGRAM_DATA_METADATA_LEN..];
+
+    println!("Program data {} ({}):", programdata_address, label);
+    println!("  account size:      {} bytes", data.len());
+    println!("  deployed at slot:  {}", slot);
+    println!("  upgrade authority: {}", authority);
+    println!("  ELF bytes:         {}", elf.len());
+}
+
+fn main() {
+    let first_build =
+        std::fs::read(PROGRAM_SO).expect("Build the program with `cargo build-sbf` first");
+    let second_build = match std::env::args().nth(1) {
+        Some(path) => {
+            std::fs::read(&path).unwrap_or_else(|err| panic!("Failed to read {}: {}", path, err))
+        }
+        None => first_build.clone(),
+    };
+
+    let program_id = read_keypair_file(PROGRAM_KEYPAIR)
+        .expect("Program keypair file not found")
+        .pubkey();
+
+    let mut svm = LiteSVM::new();
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 10_000_000_000).unwrap();
+
+    // ===== Deploy the first build =====
+    deploy_upgradeable(
+        &mut svm,
+        program_id,
+        &payer.pubkey(),
+        &first_build,
+        second_build.len(),
+    );
+    // Programs become callable the slot after they are deployed
+    svm.warp_to_slot(1);
+    print_program_data(&svm, program_id, "before upgrade");
+
+    let counter = Keypair::new();
+    let data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 }).unwrap();
+    let instruction = Instruction::new_with_bytes(
+        program_id,
+        &data,
+        vec![
+            AccountMeta::new(counter.pubkey(), true),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    );
+    send(&mut svm, &payer, &[&counter], instruction).expect("Initialize failed");
+
+    let count = increment(&mut svm, &payer, program_id, counter.pubkey());
+    println!(
+        "\nCounter {} incremented to {} by the first build\n",
+        counter.pubkey(),
+        count
+    );
+
+    // ===== Upgrade to the second build =====
+    upgrade(&mut svm, program_id, &payer, &second_build);
+    svm.warp_to_slot(2);
+    print_program_data(&svm, program_id, "after upgrade");
+
+    let upgraded_count = increment(&mut svm, &payer, program_id, counter.pubkey());
+    assert_eq!(
+        upgraded_count,
+        count + 1,
+        "Counter should keep counting after the upgrade"
+    );
+    println!(
+        "\nSame program ID, same counter: incremented to {} by the second build",
+        upgraded_count
+    );
+}
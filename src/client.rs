@@ -0,0 +1,37 @@
+//! Helpers for off-chain code that talks to the counter program
+
+use crate::{
+    state::{COMMITMENT_SEED, COUNTER_SEED, STATS_SEED},
+    ID, PROGRAM_ID_STR,
+};
+use solana_program::pubkey::Pubkey;
+
+/// Base58 program ID, the same string passed to `declare_id!`
+pub fn program_id_str() -> &'static str {
+    PROGRAM_ID_STR
+}
+
+/// The program ID declared with `declare_id!`
+pub fn program_id() -> Pubkey {
+    ID
+}
+
+/// Derive the PDA counter owned by `payer`, exactly as `InitializeCounterPda` does
+pub fn find_counter_pda(payer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COUNTER_SEED, payer.as_ref()], &ID)
+}
+
+/// Derive the counter at `index` owned by `payer`, exactly as `InitializeCounterIndexed` does
+pub fn find_indexed_counter_pda(payer: &Pubkey, index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COUNTER_SEED, payer.as_ref(), &index.to_le_bytes()], &ID)
+}
+
+/// Derive the pending-commitment PDA of `counter`, exactly as `CommitIncrement` does
+pub fn find_commitment_pda(counter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMMITMENT_SEED, counter.as_ref()], &ID)
+}
+
+/// Derive the program-wide stats PDA, exactly as `InitializeStats` does
+pub fn find_stats_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATS_SEED], &ID)
+}
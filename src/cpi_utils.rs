@@ -0,0 +1,22 @@
+//! Helpers shared by the CPI handlers
+
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, instruction::Instruction, msg,
+    program::invoke,
+};
+
+/// `invoke`, after logging the target program and data length
+///
+/// The trace line (`CPI -> <program> (<n> bytes)`) shows up in the transaction logs
+/// right before the callee's own `Program ... invoke` line.
+pub(crate) fn invoke_logged(
+    instruction: &Instruction,
+    account_infos: &[AccountInfo],
+) -> ProgramResult {
+    msg!(
+        "CPI -> {} ({} bytes)",
+        instruction.program_id,
+        instruction.data.len()
+    );
+    invoke(instruction, account_infos)
+}
@@ -1,14 +1,118 @@
 use codama::CodamaErrors;
+use solana_program::program_error::ProgramError;
 use thiserror::Error;
 
-#[derive(CodamaErrors, Error, Debug)]
+/// Errors returned by the native counter program
+///
+/// Each variant is surfaced as `ProgramError::Custom(code)` with the explicit
+/// discriminant below, so codes never shift when variants are added. The same
+/// codes and messages are emitted into the `errors` section of `idl.json`, which
+/// is what generated clients decode custom errors with.
+///
+/// Anchor numbers `#[error_code]` variants automatically, starting at 6000
+/// (`anchor_lang::error::ERROR_CODE_OFFSET`). The same failure therefore has a
+/// different code in each program:
+///
+/// | Failure          | Native (`CounterError`) | Anchor (`ErrorCode`) |
+/// |------------------|-------------------------|----------------------|
+/// | Counter overflow | 1                       | 6000                 |
+#[derive(CodamaErrors, Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
 pub enum CounterError {
     #[error("Invalid instruction data provided")]
-    InvalidInstructionData,
+    InvalidInstructionData = 0,
 
     #[error("Counter overflow occurred")]
-    CounterOverflow,
+    CounterOverflow = 1,
 
     #[error("Incorrect program ID")]
-    IncorrectProgramId,
+    IncorrectProgramId = 2,
+
+    #[error("Counter account is already initialized")]
+    AlreadyInitialized = 3,
+
+    #[error("Counter was updated too recently")]
+    RateLimited = 4,
+
+    #[error("Counter underflow occurred")]
+    CounterUnderflow = 5,
+
+    #[error("More accounts than the instruction takes")]
+    UnexpectedAccountCount = 6,
+
+    #[error("Signer is not the counter authority")]
+    InvalidAuthority = 7,
+
+    #[error("Counter account and payer must be different accounts")]
+    CounterIsPayer = 8,
+
+    #[error("Counter is disabled")]
+    CounterDisabled = 9,
+
+    #[error("Program account is not executable")]
+    ProgramNotExecutable = 10,
+
+    #[error("Instruction requires a feature this program was built without")]
+    FeatureDisabled = 11,
+
+    #[error("Counter does not hold the expected value")]
+    CasMismatch = 12,
+
+    #[error("Merkle proof does not match the whitelist root")]
+    InvalidMerkleProof = 13,
+
+    #[error("No pending commitment to reveal")]
+    NoCommitment = 14,
+
+    #[error("Revealed nonce does not match the commitment")]
+    RevealMismatch = 15,
+
+    #[error("A required account is missing")]
+    MissingAccount = 16,
+
+    #[error("Nonce is not above the last one accepted")]
+    StaleNonce = 17,
+}
+
+impl CounterError {
+    /// The code this error is surfaced with in `ProgramError::Custom`
+    pub fn code(self) -> u32 {
+        self as u32
+    }
+
+    /// The error behind a `ProgramError::Custom` code, or `None` for codes this
+    /// program doesn't return (e.g. from a newer version)
+    ///
+    /// With the `Display` impl this turns a failed transaction's custom code back
+    /// into a readable message off-chain.
+    pub fn from_code(code: u32) -> Option<Self> {
+        use CounterError::*;
+        Some(match code {
+            0 => InvalidInstructionData,
+            1 => CounterOverflow,
+            2 => IncorrectProgramId,
+            3 => AlreadyInitialized,
+            4 => RateLimited,
+            5 => CounterUnderflow,
+            6 => UnexpectedAccountCount,
+            7 => InvalidAuthority,
+            8 => CounterIsPayer,
+            9 => CounterDisabled,
+            10 => ProgramNotExecutable,
+            11 => FeatureDisabled,
+            12 => CasMismatch,
+            13 => InvalidMerkleProof,
+            14 => NoCommitment,
+            15 => RevealMismatch,
+            16 => MissingAccount,
+            17 => StaleNonce,
+            _ => return None,
+        })
+    }
+}
+
+impl From<CounterError> for ProgramError {
+    fn from(error: CounterError) -> Self {
+        ProgramError::Custom(error.code())
+    }
 }
@@ -1,14 +1,88 @@
+use crate::errors::CounterError;
 use borsh::{BorshDeserialize, BorshSerialize};
 use codama::CodamaInstructions;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
 
-#[derive(CodamaInstructions, BorshSerialize, BorshDeserialize, Debug)]
+/// Instructions of the native counter program
+///
+/// The first byte of instruction data is a stable opcode (the borsh variant index).
+/// Variants are only ever appended, so existing opcodes never change meaning:
+///
+/// | Opcode | Variant                           |
+/// |--------|-----------------------------------|
+/// | 0      | `InitializeCounter`               |
+/// | 1      | `IncrementCounter`                |
+/// | 2      | `IncrementAnchorCounter`          |
+/// | 3      | `IncrementAnchorCounterRaw`       |
+/// | 4      | `IncrementCounterSelfCpi`         |
+/// | 5      | `IncrementCounterCodamaClient`    |
+/// | 6      | `IncrementCounterMultisig`        |
+/// | 7      | `SetStep`                         |
+/// | 8      | `DecrementCounterSaturating`      |
+/// | 9      | `InitializeCounterCodamaClient`   |
+/// | 10     | `InitializeCounterPda`            |
+/// | 11     | `IncrementPdaCounterCodamaClient` |
+/// | 12     | `InitializeBigCounter`            |
+/// | 13     | `IncrementBigCounter`             |
+/// | 14     | `IncrementCounterRateLimited`     |
+/// | 15     | `SetMaxValue`                     |
+/// | 16     | `DecrementCounter`                |
+/// | 17     | `IncrementAndTransfer`            |
+/// | 18     | `DecrementCounterCodamaClient`    |
+/// | 19     | `InitializeHistoryCounter`        |
+/// | 20     | `IncrementHistoryCounter`         |
+/// | 21     | `ResetCounter`                    |
+/// | 22     | `TransferAuthority`               |
+/// | 23     | `GetCount`                        |
+/// | 24     | `IncrementCounterSaturating`      |
+/// | 25     | `ToggleEnabled`                   |
+/// | 26     | `InitializeNamedCounter`          |
+/// | 27     | `InitializeCounterIndexed`        |
+/// | 28     | `BatchIncrement`                  |
+/// | 29     | `SnapshotCounter`                 |
+/// | 30     | `RestoreCounter`                  |
+/// | 31     | `QueryRent`                       |
+/// | 32     | `LogMemo`                         |
+/// | 33     | `CompareAndIncrement`             |
+/// | 34     | `CloseCounter`                    |
+/// | 35     | `InitializeCounterIdempotent`     |
+/// | 36     | `SetDelegate`                     |
+/// | 37     | `IncrementAsDelegate`             |
+/// | 38     | `WithdrawSurplus`                 |
+/// | 39     | `InitializeWhitelistCounter`      |
+/// | 40     | `IncrementIfWhitelisted`          |
+/// | 41     | `CommitIncrement`                 |
+/// | 42     | `RevealIncrement`                 |
+/// | 43     | `RecordEpoch`                     |
+/// | 44     | `InitializeCounterWithSize`       |
+/// | 45     | `AppendLog`                       |
+/// | 46     | `InitializeCounterWithOverflowBehavior` |
+/// | 47     | `AdjustCounter`                   |
+/// | 48     | `InitAndIncrementSelfCpi`         |
+/// | 49     | `InitializeStats`                 |
+/// | 50     | `InitializeCountersBatch`         |
+/// | 51     | `IncrementCounterWithNonce`       |
+/// | 52     | `CopyCounter`                     |
+/// | 53     | `AssertCount`                     |
+/// | 54     | `ClaimAuthority`                  |
+#[derive(CodamaInstructions, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Clone)]
 pub enum CounterInstruction {
     #[codama(account(name = "counter", signer, writable))]
     #[codama(account(name = "payer", signer, writable))]
     #[codama(account(name = "system_program", default_value = program("system")))]
     InitializeCounter { initial_value: u64 },
 
+    /// Increment the counter by its step and record the current timestamp
+    ///
+    /// Every account after the counter is optional and keeps its position: pass the
+    /// program ID for an omitted one, or leave trailing ones off. Legacy counters
+    /// pass `payer` and the System Program together to be migrated to the current
+    /// layout first; the payer does not become the authority (see `ClaimAuthority`).
+    /// Passing the `stats` PDA also adds the increment to the program-wide total.
     #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "payer", optional, signer, writable))]
+    #[codama(account(name = "system_program", optional, default_value = program("system")))]
+    #[codama(account(name = "stats", optional, writable))]
     IncrementCounter,
 
     /// CPI: Increment an Anchor counter using Anchor's generated CPI client
@@ -32,4 +106,441 @@ pub enum CounterInstruction {
     #[codama(account(name = "counter", writable))]
     #[codama(account(name = "counter_program"))]
     IncrementCounterCodamaClient,
+
+    /// Increment the counter only when two distinct approvers both sign
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "first_signer", signer))]
+    #[codama(account(name = "second_signer", signer))]
+    IncrementCounterMultisig,
+
+    /// Set the amount `IncrementCounter` adds (0 keeps the original step of 1)
+    ///
+    /// Only the counter's authority may set it. Counters from an older layout are
+    /// grown to the current one, with the authority covering the extra rent; legacy
+    /// 8-byte counters predate the authority field and keep the step of 1.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    SetStep { step: u64 },
+
+    /// Decrement the counter by 1, saturating at zero
+    ///
+    /// A checked decrement (`checked_sub`) would fail the whole transaction on a
+    /// zero counter; this variant uses `saturating_sub` so 0 simply stays 0.
+    #[codama(account(name = "counter", writable))]
+    DecrementCounterSaturating,
+
+    /// Self-CPI: Initialize a counter using the Codama-generated CPI client
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    #[codama(account(name = "counter_program"))]
+    InitializeCounterCodamaClient { initial_value: u64 },
+
+    /// Initialize a counter at the PDA derived from `["counter", payer]`
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeCounterPda { initial_value: u64 },
+
+    /// Self-CPI: Increment a PDA counter using the Codama CPI client, signed with the PDA seeds
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "payer"))]
+    #[codama(account(name = "counter_program"))]
+    IncrementPdaCounterCodamaClient,
+
+    /// Initialize a 128-bit counter for counts that could outgrow a u64
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeBigCounter { initial_value: u128 },
+
+    /// Increment a 128-bit counter by one
+    #[codama(account(name = "counter", writable))]
+    IncrementBigCounter,
+
+    /// Increment only if at least `min_interval_secs` passed since `last_updated`
+    #[codama(account(name = "counter", writable))]
+    IncrementCounterRateLimited { min_interval_secs: i64 },
+
+    /// Cap the value `IncrementCounter` may reach (0 removes the cap)
+    ///
+    /// Only the counter's authority may set it; it also pays for growing older counters.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    SetMaxValue { max_value: u64 },
+
+    /// Decrement the counter by its step, failing instead of going below zero
+    ///
+    /// The checked counterpart of `DecrementCounterSaturating` and the exact
+    /// inverse of `IncrementCounter`.
+    #[codama(account(name = "counter", writable))]
+    DecrementCounter,
+
+    /// Increment the counter and CPI into SPL Token to move 1 token from `source` to `destination`
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "source", writable))]
+    #[codama(account(name = "destination", writable))]
+    #[codama(account(name = "authority", signer))]
+    #[codama(account(name = "token_program", default_value = public_key("TokenkegQfeZyiNwAJbNbGqPFXCWuBvf9Ss623VQ5DA")))]
+    IncrementAndTransfer,
+
+    /// Self-CPI: Decrement counter using the Codama-generated CPI client
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "counter_program"))]
+    DecrementCounterCodamaClient,
+
+    /// Initialize a counter that remembers its last 8 values
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeHistoryCounter { initial_value: u64 },
+
+    /// Increment a history counter by one and push the new value into its ring
+    #[codama(account(name = "counter", writable))]
+    IncrementHistoryCounter,
+
+    /// Reset the count to zero (authority only)
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    ResetCounter,
+
+    /// Hand the counter's authority to `new_authority` (current authority only)
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    TransferAuthority { new_authority: Pubkey },
+
+    /// Read-only: return the current count (u64, little-endian) via return data
+    #[codama(account(name = "counter"))]
+    GetCount,
+
+    /// Increment the counter by 1, saturating at `max_value` (or `u64::MAX` without a cap)
+    ///
+    /// For monotonic metrics that should never fail a transaction: a counter at
+    /// its cap simply stays there. Paused counters still fail with `CounterDisabled`.
+    #[codama(account(name = "counter", writable))]
+    IncrementCounterSaturating,
+
+    /// Pause or unpause the counter by flipping its `enabled` flag (authority only)
+    ///
+    /// While paused, every instruction that changes the count fails with
+    /// `CounterDisabled`. Legacy counters are grown to the current layout, with
+    /// the authority covering the extra rent.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    ToggleEnabled,
+
+    /// Initialize a counter with a name of up to 32 bytes (may be empty)
+    ///
+    /// The account is sized to the serialized name, not a fixed maximum.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeNamedCounter { initial_value: u64, name: String },
+
+    /// Initialize the counter at the PDA derived from `["counter", payer, index]`
+    ///
+    /// `index` is seeded as little-endian bytes, so one payer can own many counters.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeCounterIndexed { index: u64, initial_value: u64 },
+
+    /// Increment every counter passed (one or more, each writable) by its step
+    ///
+    /// Stops early instead of failing when compute units run low. The number of
+    /// counters actually incremented (u64, little-endian) is set as return data.
+    #[codama(account(name = "counter", writable))]
+    BatchIncrement,
+
+    /// Save the current count so `RestoreCounter` can roll back to it
+    ///
+    /// Only the counter's authority may take a snapshot. Legacy counters are
+    /// grown to the current layout, with the authority covering the extra rent.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    SnapshotCounter,
+
+    /// Roll the count back to the last snapshot (0 if none was taken)
+    ///
+    /// Only the counter's authority may restore. Fails with `CounterDisabled`
+    /// while paused and `CounterOverflow` if the snapshot is above `max_value`.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    RestoreCounter,
+
+    /// Read-only: return the rent-exempt minimum for a `CounterAccount` (u64, little-endian)
+    /// via return data
+    QueryRent,
+
+    /// Increment the counter and CPI into SPL Memo to log `message`
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "memo_program", default_value = public_key("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr")))]
+    LogMemo { message: String },
+
+    /// Increment the counter only if it currently holds `expected`
+    ///
+    /// Optimistic concurrency: a writer working from a stale read fails with
+    /// `CasMismatch` instead of silently building on a value that has moved.
+    #[codama(account(name = "counter", writable))]
+    CompareAndIncrement { expected: u64 },
+
+    /// Close the counter and send its rent to `recipient`, gated by the stored authority
+    ///
+    /// Works for PDA counters too: the program owns the account, so the counter
+    /// never has to sign.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    #[codama(account(name = "recipient", writable))]
+    CloseCounter,
+
+    /// Initialize the counter, or succeed without changes if it already is one
+    ///
+    /// Lets clients retry an initialize without first checking whether it landed.
+    /// An account holding any other type fails with `AlreadyInitialized`.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeCounterIdempotent { initial_value: u64 },
+
+    /// Set or clear (`None`) the key allowed to `IncrementAsDelegate`, authority only
+    ///
+    /// Legacy counters are grown to the current layout, with the authority
+    /// covering the extra rent.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    SetDelegate { delegate: Option<Pubkey> },
+
+    /// Increment the counter, signed by either the stored authority or its delegate
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "signer", signer))]
+    IncrementAsDelegate,
+
+    /// Move every lamport above the counter's rent-exempt minimum to `destination`,
+    /// authority only
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    #[codama(account(name = "destination", writable))]
+    WithdrawSurplus,
+
+    /// Initialize a counter that only whitelisted leaves may increment
+    ///
+    /// `merkle_root` is the root of a keccak tree whose leaves are
+    /// `merkle::hash_leaf` of each allowed wallet, combined with `merkle::hash_pair`.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeWhitelistCounter {
+        initial_value: u64,
+        merkle_root: [u8; 32],
+    },
+
+    /// Increment a whitelist counter by one if `proof` shows the signing `member` is in its tree
+    ///
+    /// The leaf is `merkle::hash_leaf` of the member's address, never supplied by the caller.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "member", signer))]
+    IncrementIfWhitelisted { proof: Vec<[u8; 32]> },
+
+    /// Commit to a secret nonce (`hash` = `CommitmentAccount::hash_nonce(nonce)`), authority only
+    ///
+    /// The commitment lives at the PDA `["commitment", counter]`, paid for by the
+    /// authority; only one can be pending per counter.
+    #[codama(account(name = "counter"))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "commitment", writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    CommitIncrement { hash: [u8; 32] },
+
+    /// Reveal the committed nonce: increments and closes the commitment, refunding
+    /// its rent to the authority
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "commitment", writable))]
+    RevealIncrement { nonce: u64 },
+
+    /// Store the current epoch from the Clock sysvar in `last_epoch`
+    ///
+    /// Only the counter's authority may record the epoch. Legacy counters are
+    /// grown to the current layout, with the authority covering the extra rent.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    RecordEpoch,
+
+    /// Initialize a counter in an account of `8 + reserved` bytes, pre-sized for later fields
+    ///
+    /// At least `CounterAccount::LEN` bytes, so the payer is always stored as the
+    /// authority, and at most `CounterAccount::MAX_SPACE`.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeCounterWithSize { initial_value: u64, reserved: u16 },
+
+    /// Append `entry` to the log kept after the counter fields, growing the account to fit it
+    ///
+    /// Only the counter's authority may append. The log is a u32 entry count
+    /// followed by the entries, so space reserved by `InitializeCounterWithSize` is
+    /// used before the account grows. The authority tops up the rent for a larger
+    /// account. Legacy counters are migrated first, so the log always starts at
+    /// `CounterAccount::LEN`.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    AppendLog { entry: u64 },
+
+    /// Initialize a counter that handles overflow in `IncrementCounter` as configured
+    ///
+    /// `overflow_behavior` is an `OverflowBehavior`: 0 errors (the default of every
+    /// other initializer), 1 wraps around and 2 saturates at `u64::MAX`. Anything
+    /// else is rejected with `InvalidArgument`.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeCounterWithOverflowBehavior {
+        initial_value: u64,
+        overflow_behavior: u8,
+    },
+
+    /// Add a signed `delta` to the counter, covering both directions in one instruction
+    ///
+    /// Only the counter's authority may adjust it. Fails with `CounterOverflow` above
+    /// `u64::MAX` or the counter's `max_value`, and `CounterUnderflow` below zero.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer))]
+    AdjustCounter { delta: i64 },
+
+    /// Initialize a counter, then increment it through a self-CPI into `IncrementCounter`
+    ///
+    /// Composes two instructions into one, leaving the counter at `initial_value + 1`.
+    #[codama(account(name = "counter", signer, writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    #[codama(account(name = "counter_program"))]
+    InitAndIncrementSelfCpi { initial_value: u64 },
+
+    /// Create the program-wide stats PDA `["stats"]` that `IncrementCounter` can update
+    #[codama(account(name = "stats", writable))]
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    InitializeStats,
+
+    /// Create `count` counters at `initial_value` in one instruction, all paid for by `payer`
+    ///
+    /// The counters (each a signer, writable) follow the payer and System Program,
+    /// exactly `count` of them.
+    #[codama(account(name = "payer", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    #[codama(account(name = "counter", signer, writable))]
+    InitializeCountersBatch { count: u8, initial_value: u64 },
+
+    /// Increment the counter by its step, only if `nonce` is above the last one accepted
+    ///
+    /// Replays and out-of-order calls fail with `StaleNonce`, so each signed
+    /// increment can land at most once.
+    #[codama(account(name = "counter", writable))]
+    IncrementCounterWithNonce { nonce: u64 },
+
+    /// Overwrite the destination counter's count with the source counter's
+    ///
+    /// Only the destination's authority may copy onto it. Fails with
+    /// `CounterDisabled` while the destination is paused and `CounterOverflow`
+    /// if the copied count is above its `max_value`.
+    #[codama(account(name = "source"))]
+    #[codama(account(name = "destination", writable))]
+    #[codama(account(name = "authority", signer))]
+    CopyCounter,
+
+    /// Fail with `CasMismatch` unless the counter holds `expected`; nothing is written
+    ///
+    /// A guard for composed transactions: placed before other instructions, it
+    /// aborts the whole transaction if the counter has drifted.
+    #[codama(account(name = "counter"))]
+    AssertCount { expected: u64 },
+
+    /// Give a counter created before authorities existed its first authority
+    ///
+    /// Only works while the stored authority is unset, and only for the counter's
+    /// creator: either the counter's own keypair also signs, or the counter is the
+    /// authority's `["counter", authority]` PDA. Legacy counters are grown to the
+    /// current layout first, with the authority covering the extra rent.
+    #[codama(account(name = "counter", writable))]
+    #[codama(account(name = "authority", signer, writable))]
+    #[codama(account(name = "system_program", default_value = program("system")))]
+    ClaimAuthority,
+}
+
+impl CounterInstruction {
+    /// Highest opcode this program understands
+    pub const MAX_OPCODE: u8 = 54;
+
+    /// Decode instruction data, checking the opcode before the borsh payload
+    ///
+    /// Unknown opcodes (e.g. from a newer client) and payloads that don't decode
+    /// are both rejected with `CounterError::InvalidInstructionData` instead of being
+    /// misread. Either way the reason is logged, since the returned error alone
+    /// doesn't say what failed to parse.
+    pub fn unpack(instruction_data: &[u8]) -> Result<Self, ProgramError> {
+        let (&opcode, _) = instruction_data.split_first().ok_or_else(|| {
+            msg!("Error: Empty instruction data");
+            CounterError::InvalidInstructionData
+        })?;
+        if opcode > Self::MAX_OPCODE {
+            msg!(
+                "Error: Unexpected variant index {} (highest known opcode is {})",
+                opcode,
+                Self::MAX_OPCODE
+            );
+            return Err(CounterError::InvalidInstructionData.into());
+        }
+
+        Self::try_from_slice(instruction_data).map_err(|err| {
+            msg!("Error: Failed to deserialize opcode {}: {}", opcode, err);
+            CounterError::InvalidInstructionData.into()
+        })
+    }
+}
+
+/// Instructions of the Anchor counter program, for raw CPIs without its crate
+///
+/// Anchor instruction data is an 8-byte discriminator, the first 8 bytes of
+/// `sha256("global:<instruction_name>")`, followed by the borsh-encoded
+/// arguments. The discriminators are listed in
+/// anchor-counter/target/idl/anchor_counter.json.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnchorIx {
+    InitializeCounter { initial_value: u64 },
+    IncrementCounter,
+    IncrementMany,
+    IncrementNativeCounter,
+}
+
+impl AnchorIx {
+    pub const INITIALIZE_COUNTER_DISCRIMINATOR: [u8; 8] = [67, 89, 100, 87, 231, 172, 35, 124];
+    pub const INCREMENT_COUNTER_DISCRIMINATOR: [u8; 8] = [16, 125, 2, 171, 73, 24, 207, 229];
+    pub const INCREMENT_MANY_DISCRIMINATOR: [u8; 8] = [138, 226, 160, 220, 47, 40, 184, 125];
+    pub const INCREMENT_NATIVE_COUNTER_DISCRIMINATOR: [u8; 8] =
+        [103, 124, 36, 136, 151, 180, 192, 39];
+
+    pub fn discriminator(&self) -> [u8; 8] {
+        match self {
+            AnchorIx::InitializeCounter { .. } => Self::INITIALIZE_COUNTER_DISCRIMINATOR,
+            AnchorIx::IncrementCounter => Self::INCREMENT_COUNTER_DISCRIMINATOR,
+            AnchorIx::IncrementMany => Self::INCREMENT_MANY_DISCRIMINATOR,
+            AnchorIx::IncrementNativeCounter => Self::INCREMENT_NATIVE_COUNTER_DISCRIMINATOR,
+        }
+    }
+
+    /// Instruction data: the discriminator, then the arguments
+    pub fn to_data(&self) -> Vec<u8> {
+        let mut data = self.discriminator().to_vec();
+        if let AnchorIx::InitializeCounter { initial_value } = self {
+            data.extend_from_slice(&initial_value.to_le_bytes());
+        }
+        data
+    }
 }
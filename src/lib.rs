@@ -1,14 +1,26 @@
+pub mod client;
+mod cpi_utils;
 mod errors;
 mod instructions;
+pub mod merkle;
 mod processor;
 mod state;
+#[cfg(test)]
+mod test_support;
 
+pub use client::{
+    find_commitment_pda, find_counter_pda, find_indexed_counter_pda, find_stats_pda, program_id,
+    program_id_str,
+};
 pub use errors::*;
 pub use instructions::*;
 pub use state::*;
 
+/// Base58 program ID, the single literal behind both `ID` and `program_id_str`
+pub const PROGRAM_ID_STR: &str = "4FE9JYc8rtvbHd3U7dmDVNhtvQdb7xwdLvRnHiHCs27w";
+
 use solana_program::declare_id;
-declare_id!("4FE9JYc8rtvbHd3U7dmDVNhtvQdb7xwdLvRnHiHCs27w");
+declare_id!(PROGRAM_ID_STR);
 
 
 pub use crate::ID as COUNTER_PROGRAM_ID;
@@ -30,78 +42,27 @@ pub fn process_instruction(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::test_support::types::{AccountMeta, Instruction, ProgramPubkey, Pubkey};
+    use crate::test_support::*;
     use borsh::BorshDeserialize;
     use litesvm::LiteSVM;
     use solana_sdk::{
         account::ReadableAccount,
-        instruction::{AccountMeta, Instruction},
+        instruction::InstructionError,
         message::Message,
-        pubkey::Pubkey,
-        signature::{read_keypair_file, Keypair, Signer},
-        transaction::Transaction,
+        signature::{Keypair, Signer},
+        transaction::{Transaction, TransactionError},
     };
 
-    // System Program ID - well-known constant "11111111111111111111111111111111"
-    const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
-
     #[test]
     fn test_counter_program() {
-        // Create a new LiteSVM instance
-        let mut svm = LiteSVM::new();
-
-        // Create a keypair for the transaction payer
-        let payer = Keypair::new();
-
-        // Find our program ID (you can get this from your deployed program)
-        let program_keypair = read_keypair_file("target/deploy/counter_program-keypair.json")
-            .expect("Program keypair file not found");
-        let program_id = program_keypair.pubkey();
-
-        // Airdrop some SOL to the payer
-        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
-
-        // Deploy our program
-        svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
-            .unwrap();
-
-        // Create a keypair for the counter account
-        let counter_keypair = Keypair::new();
+        let (mut svm, payer, program_id) = setup_native_svm();
 
         // ===== Test 1: Initialize Counter =====
-
-        // Serialize the initialize instruction data
         let initial_value = 100u64;
-        let init_instruction_data =
-            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value })
-                .expect("Failed to serialize instruction");
-
-        let initialize_instruction = Instruction::new_with_bytes(
-            program_id,
-            &init_instruction_data,
-            vec![
-                AccountMeta::new(counter_keypair.pubkey(), true),
-                AccountMeta::new(payer.pubkey(), true),
-                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-            ],
-        );
-
-        // Create transaction
-        let message = Message::new(&[initialize_instruction], Some(&payer.pubkey()));
-        let transaction =
-            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
-
-        // Send transaction
-        let result = svm.send_transaction(transaction);
-        assert!(result.is_ok(), "Initialize transaction should succeed");
-
-        // Read the counter account data
-        let account = svm
-            .get_account(&counter_keypair.pubkey())
-            .expect("Account should exist");
-
-        let counter: CounterAccount = CounterAccount::try_from_slice(account.data())
-            .expect("Failed to deserialize counter account");
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, initial_value);
 
+        let counter = read_counter(&svm, &counter_keypair.pubkey());
         assert_eq!(
             counter.count, initial_value,
             "Counter should be initialized to 100"
@@ -128,14 +89,7 @@ mod test {
         let result = svm.send_transaction(transaction);
         assert!(result.is_ok(), "Increment transaction should succeed");
 
-        // Read the updated counter account data
-        let account = svm
-            .get_account(&counter_keypair.pubkey())
-            .expect("Account should exist");
-
-        let counter: CounterAccount = CounterAccount::try_from_slice(account.data())
-            .expect("Failed to deserialize counter account");
-
+        let counter = read_counter(&svm, &counter_keypair.pubkey());
         assert_eq!(
             counter.count,
             initial_value + 1,
@@ -149,90 +103,25 @@ mod test {
 
     #[test]
     fn test_cpi_to_anchor_counter() {
-        // Create a new LiteSVM instance
-        let mut svm = LiteSVM::new();
-
-        // Create a keypair for the transaction payer
-        let payer = Keypair::new();
-
-        // Load our native program
-        let native_program_keypair =
-            read_keypair_file("target/deploy/counter_program-keypair.json")
-                .expect("Native program keypair file not found");
-        let native_program_id = native_program_keypair.pubkey();
-
-        // Load the Anchor program
-        let anchor_program_keypair =
-            read_keypair_file("anchor-counter/target/deploy/anchor_counter-keypair.json")
-                .expect("Anchor program keypair file not found");
-        let anchor_program_id = anchor_program_keypair.pubkey();
-
-        // Airdrop some SOL to the payer
-        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
-
-        // Deploy both programs
-        svm.add_program_from_file(native_program_id, "target/deploy/counter_program.so")
-            .unwrap();
-        svm.add_program_from_file(
-            anchor_program_id,
-            "anchor-counter/target/deploy/anchor_counter.so",
-        )
-        .unwrap();
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
 
         println!("Native Program ID: {}", native_program_id);
         println!("Anchor Program ID: {}", anchor_program_id);
 
-        // Create a keypair for the Anchor counter account
-        let anchor_counter_keypair = Keypair::new();
-
         // ===== Test 1: Initialize Anchor Counter =====
         println!("\n=== Initializing Anchor Counter ===");
 
-        // Anchor's initialize_counter discriminator (from IDL)
-        let init_discriminator: [u8; 8] = [67, 89, 100, 87, 231, 172, 35, 124];
-
-        // Serialize the initial value (u64)
         let initial_value = 50u64;
-        let mut init_data = init_discriminator.to_vec();
-        init_data.extend_from_slice(&initial_value.to_le_bytes());
-
-        let initialize_anchor_instruction = Instruction::new_with_bytes(
-            anchor_program_id,
-            &init_data,
-            vec![
-                AccountMeta::new(anchor_counter_keypair.pubkey(), true),
-                AccountMeta::new(payer.pubkey(), true),
-                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-            ],
-        );
-
-        let message = Message::new(&[initialize_anchor_instruction], Some(&payer.pubkey()));
-        let transaction = Transaction::new(
-            &[&payer, &anchor_counter_keypair],
-            message,
-            svm.latest_blockhash(),
-        );
-
-        let result = svm.send_transaction(transaction);
-        assert!(
-            result.is_ok(),
-            "Initialize Anchor counter transaction should succeed: {:?}",
-            result
-        );
+        let anchor_counter_keypair =
+            init_anchor_counter(&mut svm, &payer, anchor_program_id, initial_value);
 
-        // Read the Anchor counter account data
-        let account = svm
-            .get_account(&anchor_counter_keypair.pubkey())
-            .expect("Anchor counter account should exist");
-
-        // Anchor counter has 8-byte discriminator + 8-byte count + 32-byte authority
-        assert!(
-            account.data().len() >= 16,
-            "Account data too small: {}",
-            account.data().len()
-        );
-        let count = u64::from_le_bytes(account.data()[8..16].try_into().unwrap());
+        let (count, authority) = read_anchor_counter(&svm, &anchor_counter_keypair.pubkey());
         assert_eq!(count, initial_value, "Anchor counter should be initialized");
+        assert_eq!(
+            authority,
+            payer.pubkey(),
+            "The payer should be the authority"
+        );
         println!("Anchor counter initialized with value: {}", count);
 
         // ===== Test 2: CPI from Native to Anchor =====
@@ -262,12 +151,7 @@ mod test {
             result
         );
 
-        // Read the updated Anchor counter account data
-        let account = svm
-            .get_account(&anchor_counter_keypair.pubkey())
-            .expect("Anchor counter account should exist");
-
-        let count = u64::from_le_bytes(account.data()[8..16].try_into().unwrap());
+        let count = read_anchor_count(&svm, &anchor_counter_keypair.pubkey());
         assert_eq!(
             count,
             initial_value + 1,
@@ -283,33 +167,9 @@ mod test {
     fn test_cpi_raw_and_self() {
         println!("\n🧪 Testing Raw CPI and Self-CPI...");
 
-        // Create a new LiteSVM instance
-        let mut svm = LiteSVM::new();
-
-        // Load our native program
-        let native_program_keypair =
-            read_keypair_file("target/deploy/counter_program-keypair.json")
-                .expect("Native program keypair file not found");
-        let native_program_id = native_program_keypair.pubkey();
-
-        // Load the Anchor program
-        let anchor_program_keypair =
-            read_keypair_file("anchor-counter/target/deploy/anchor_counter-keypair.json")
-                .expect("Anchor program keypair file not found");
-        let anchor_program_id = anchor_program_keypair.pubkey();
-
-        // Airdrop to payer
-        let payer = Keypair::new();
-        svm.airdrop(&payer.pubkey(), 2_000_000_000).unwrap();
-
-        // Deploy both programs
-        svm.add_program_from_file(native_program_id, "target/deploy/counter_program.so")
-            .unwrap();
-        svm.add_program_from_file(
-            anchor_program_id,
-            "anchor-counter/target/deploy/anchor_counter.so",
-        )
-        .unwrap();
+        // Deployed at the Codama client's ID so the Codama CPI in Test 3 reaches it
+        let (mut svm, payer, native_program_id, anchor_program_id) =
+            setup_svm_at(codama_program_id());
 
         println!("Native Program ID: {}", native_program_id);
         println!("Anchor Program ID: {}", anchor_program_id);
@@ -317,30 +177,9 @@ mod test {
         // ===== Test 1: IncrementAnchorCounterRaw (Manual Discriminator) =====
         println!("\n=== Test 1: Manual Discriminator CPI ===");
 
-        let anchor_counter = Keypair::new();
-
-        // Initialize Anchor counter
         let initial_value = 200u64;
-        let init_discriminator: [u8; 8] = [67, 89, 100, 87, 231, 172, 35, 124];
-        let mut init_data = init_discriminator.to_vec();
-        init_data.extend_from_slice(&initial_value.to_le_bytes());
-
-        let system_program_id = Pubkey::from([0; 32]); // All zeros = system program
-        let init_anchor_tx = Transaction::new_signed_with_payer(
-            &[Instruction::new_with_bytes(
-                anchor_program_id,
-                &init_data,
-                vec![
-                    AccountMeta::new(anchor_counter.pubkey(), true),
-                    AccountMeta::new_readonly(payer.pubkey(), true),
-                    AccountMeta::new_readonly(system_program_id, false),
-                ],
-            )],
-            Some(&payer.pubkey()),
-            &[&payer, &anchor_counter],
-            svm.latest_blockhash(),
-        );
-        svm.send_transaction(init_anchor_tx).unwrap();
+        let anchor_counter =
+            init_anchor_counter(&mut svm, &payer, anchor_program_id, initial_value);
 
         // Call IncrementAnchorCounterRaw (variant 3)
         let increment_raw_data = borsh::to_vec(&CounterInstruction::IncrementAnchorCounterRaw)
@@ -362,38 +201,15 @@ mod test {
         svm.send_transaction(increment_raw_tx).unwrap();
 
         // Verify
-        let anchor_account = svm.get_account(&anchor_counter.pubkey()).unwrap();
-        let count = u64::from_le_bytes(anchor_account.data()[8..16].try_into().unwrap());
+        let count = read_anchor_count(&svm, &anchor_counter.pubkey());
         assert_eq!(count, initial_value + 1);
         println!("✅ Manual CPI: {} -> {}", initial_value, count);
 
         // ===== Test 2: IncrementCounterSelfCpi (Self-CPI) =====
         println!("\n=== Test 2: Self-CPI ===");
 
-        let native_counter = Keypair::new();
-
-        // Initialize native counter
         let native_initial = 99u64;
-        let init_native_data = borsh::to_vec(&CounterInstruction::InitializeCounter {
-            initial_value: native_initial,
-        })
-        .expect("Failed to serialize");
-
-        let init_native_tx = Transaction::new_signed_with_payer(
-            &[Instruction::new_with_bytes(
-                native_program_id,
-                &init_native_data,
-                vec![
-                    AccountMeta::new(native_counter.pubkey(), true),
-                    AccountMeta::new(payer.pubkey(), true),
-                    AccountMeta::new_readonly(system_program_id, false),
-                ],
-            )],
-            Some(&payer.pubkey()),
-            &[&payer, &native_counter],
-            svm.latest_blockhash(),
-        );
-        svm.send_transaction(init_native_tx).unwrap();
+        let native_counter = init_counter(&mut svm, &payer, native_program_id, native_initial);
 
         // Call IncrementCounterSelfCpi (variant 4)
         let self_cpi_data = borsh::to_vec(&CounterInstruction::IncrementCounterSelfCpi)
@@ -414,8 +230,7 @@ mod test {
         svm.send_transaction(self_cpi_tx).unwrap();
 
         // Verify
-        let native_account = svm.get_account(&native_counter.pubkey()).unwrap();
-        let final_count = CounterAccount::try_from_slice(&native_account.data()).unwrap();
+        let final_count = read_counter(&svm, &native_counter.pubkey());
         assert_eq!(final_count.count, native_initial + 1);
         println!("✅ Self-CPI: {} -> {}", native_initial, final_count.count);
 
@@ -423,31 +238,8 @@ mod test {
         println!("\n=== Test 3: Codama-generated CPI Client ===");
 
         // Create a counter for Codama CPI test
-        let codama_counter = Keypair::new();
         let codama_initial = 99u64;
-
-        // Initialize counter
-        let init_codama_data = borsh::to_vec(&CounterInstruction::InitializeCounter {
-            initial_value: codama_initial,
-        })
-        .expect("Failed to serialize");
-
-        let init_codama_tx = Transaction::new_signed_with_payer(
-            &[Instruction::new_with_bytes(
-                native_program_id,
-                &init_codama_data,
-                vec![
-                    AccountMeta::new(codama_counter.pubkey(), true),
-                    AccountMeta::new(payer.pubkey(), true),
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                ],
-            )],
-            Some(&payer.pubkey()),
-            &[&payer, &codama_counter],
-            svm.latest_blockhash(),
-        );
-        svm.send_transaction(init_codama_tx)
-            .expect("Failed to initialize codama counter");
+        let codama_counter = init_counter(&mut svm, &payer, native_program_id, codama_initial);
 
         println!("   Counter initialized: {}", codama_initial);
 
@@ -472,41 +264,27 @@ mod test {
         );
 
         let result = svm.send_transaction(codama_cpi_tx);
+        assert!(
+            result.is_ok(),
+            "Codama CPI should succeed: {:?}",
+            result.err()
+        );
 
-        if result.is_ok() {
-            // Verify the counter was incremented via Codama CPI
-            let codama_account = svm
-                .get_account(&codama_counter.pubkey())
-                .expect("Codama counter account should exist");
-            let codama_final = CounterAccount::try_from_slice(&codama_account.data())
-                .expect("Failed to deserialize counter");
-
-            assert_eq!(
-                codama_final.count,
-                codama_initial + 1,
-                "Counter should be incremented via Codama CPI client"
-            );
-
-            println!("✅ Codama Client CPI executed successfully!");
-            println!(
-                "   {} -> {} (via IncrementCounterCpiBuilder)",
-                codama_initial, codama_final.count
-            );
-            println!("   Processor: process_increment_counter_codama_client()");
-            println!("   Uses: codama_client::instructions::IncrementCounterCpiBuilder");
-        } else {
-            // If the program ID doesn't match the declared ID, the test will fail
-            println!("⚠️  Codama CPI test failed (likely program ID mismatch)");
-            println!("   Error: {:?}", result.err());
-            println!("   Actual program ID: {}", native_program_id);
+        // Verify the counter was incremented via Codama CPI
+        let codama_final = read_counter(&svm, &codama_counter.pubkey());
+        assert_eq!(
+            codama_final.count,
+            codama_initial + 1,
+            "Counter should be incremented via Codama CPI client"
+        );
 
-            // Still verify the instruction can be serialized
-            assert_eq!(
-                codama_cpi_data[0], 5,
-                "IncrementCounterCodamaClient should be variant 5"
-            );
-            println!("✅ Instruction serialization verified (variant 5)");
-        }
+        println!("✅ Codama Client CPI executed successfully!");
+        println!(
+            "   {} -> {} (via IncrementCounterCpiBuilder)",
+            codama_initial, codama_final.count
+        );
+        println!("   Processor: process_increment_counter_codama_client()");
+        println!("   Uses: codama_client::instructions::IncrementCounterCpiBuilder");
 
         println!("\n🎉 All CPI Methods Tested Successfully!");
         println!("   ✓ Anchor CPI Client (variant 2)");
@@ -514,4 +292,5211 @@ mod test {
         println!("   ✓ Codama Pattern Self-CPI (variant 4)");
         println!("   ✓ Codama CPI Client (variant 5)");
     }
+
+    #[test]
+    fn test_initialize_rejects_fake_system_program() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let counter_keypair = Keypair::new();
+
+        // A random account posing as the System Program
+        let fake_system_program = Keypair::new().pubkey();
+
+        let init_instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 1 })
+                .expect("Failed to serialize instruction");
+
+        let initialize_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_instruction_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(fake_system_program, false),
+            ],
+        );
+
+        let message = Message::new(&[initialize_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+
+        let result = svm.send_transaction(transaction);
+        let err = result.expect_err("Initialize with a fake system program should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+        );
+
+        // The counter account must not have been created
+        assert!(svm.get_account(&counter_keypair.pubkey()).is_none());
+    }
+
+    #[test]
+    fn test_initialize_twice_fails_with_already_initialized() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let counter_keypair = Keypair::new();
+
+        let initialize = |initial_value: u64| {
+            let data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value })
+                .expect("Failed to serialize instruction");
+            Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), true),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            )
+        };
+
+        // ===== First initialization succeeds =====
+        let message = Message::new(&[initialize(1)], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let logs = run_and_get_logs(&mut svm, transaction);
+        let initialized =
+            processor::format_count_log("initialize", "Counter initialized with value", 1);
+        assert!(
+            logs.iter().any(|log| log.ends_with(&initialized)),
+            "Missing {:?} in {:#?}",
+            initialized,
+            logs
+        );
+
+        // ===== Second initialization is rejected with a clear error =====
+        let message = Message::new(&[initialize(2)], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Second initialize should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::AlreadyInitialized as u32)
+            )
+        );
+
+        // The original value is untouched
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 1);
+    }
+
+    #[test]
+    fn test_counter_account_pack_matches_borsh() {
+        use solana_program::program_pack::Pack;
+
+        let counter = || CounterAccount {
+            count: 0x0102_0304_0506_0708,
+            step: 7,
+            last_updated: -42,
+            max_value: 1_000,
+            authority: ProgramPubkey::new_from_array([9; 32]),
+            enabled: false,
+            snapshot: 0x1122_3344,
+            delegate: Some(ProgramPubkey::new_from_array([5; 32])),
+            last_epoch: 0x5566,
+            overflow_behavior: OverflowBehavior::Saturate as u8,
+            nonce: 0x7788,
+        };
+
+        // Pack into a fixed-size buffer, SPL style
+        let mut packed = [0u8; CounterAccount::LEN];
+        CounterAccount::pack(counter(), &mut packed).expect("Failed to pack counter");
+
+        // The packed bytes must match the borsh encoding
+        let borsh_bytes = borsh::to_vec(&counter()).expect("Failed to serialize counter");
+        assert_eq!(packed.as_slice(), borsh_bytes.as_slice());
+
+        // And round-trip back to the same value
+        let unpacked = CounterAccount::unpack_unchecked(&packed).expect("Failed to unpack counter");
+        assert_eq!(unpacked, counter());
+
+        // Without a delegate the key bytes are zeroed, not dropped, so the layout holds
+        let undelegated = CounterAccount {
+            delegate: None,
+            ..counter()
+        };
+        CounterAccount::pack(undelegated.clone(), &mut packed).expect("Failed to pack counter");
+        let borsh_bytes = borsh::to_vec(&undelegated).expect("Failed to serialize counter");
+        assert_eq!(packed.as_slice(), borsh_bytes.as_slice());
+        assert!(packed[73..106].iter().all(|&byte| byte == 0));
+        assert_eq!(
+            CounterAccount::try_from_slice(&packed).unwrap(),
+            undelegated
+        );
+
+        // Wrong-sized buffers are rejected
+        assert!(CounterAccount::unpack_unchecked(&packed[..4]).is_err());
+    }
+
+    #[test]
+    fn test_generated_counter_account_decodes_every_field() {
+        let counter = CounterAccount {
+            count: 0x0102_0304_0506_0708,
+            step: 7,
+            last_updated: -42,
+            max_value: 1_000,
+            authority: ProgramPubkey::new_from_array([9; 32]),
+            enabled: false,
+            snapshot: 0x1122_3344,
+            delegate: None,
+            last_epoch: 0x5566,
+            overflow_behavior: OverflowBehavior::Saturate as u8,
+            nonce: 0x7788,
+        };
+
+        for delegate in [None, Some(ProgramPubkey::new_from_array([5; 32]))] {
+            let counter = CounterAccount {
+                delegate,
+                ..counter.clone()
+            };
+            let data = borsh::to_vec(&counter).expect("Failed to serialize counter");
+            let generated = codama_client::accounts::CounterAccount::from_bytes(&data)
+                .expect("Failed to decode with the Codama account type");
+
+            assert_eq!(generated.count, counter.count);
+            assert_eq!(generated.step, counter.step);
+            assert_eq!(generated.last_updated, counter.last_updated);
+            assert_eq!(generated.max_value, counter.max_value);
+            assert_eq!(generated.authority.to_bytes(), counter.authority.to_bytes());
+            assert_eq!(generated.enabled, counter.enabled);
+            assert_eq!(generated.snapshot, counter.snapshot);
+            assert_eq!(generated.delegate.0, counter.delegate.is_some());
+            assert_eq!(
+                generated.delegate.1.to_bytes(),
+                counter.delegate.unwrap_or_default().to_bytes()
+            );
+            assert_eq!(generated.last_epoch, counter.last_epoch);
+            assert_eq!(generated.overflow_behavior, counter.overflow_behavior);
+            assert_eq!(generated.nonce, counter.nonce);
+        }
+    }
+
+    #[test]
+    fn test_increment_counter_multisig() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // ===== Initialize Counter =====
+        let counter_keypair = Keypair::new();
+        let initial_value = 10u64;
+        let init_instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value })
+                .expect("Failed to serialize instruction");
+
+        let initialize_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_instruction_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[initialize_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        let first_approver = Keypair::new();
+        let second_approver = Keypair::new();
+        let multisig_data = borsh::to_vec(&CounterInstruction::IncrementCounterMultisig)
+            .expect("Failed to serialize instruction");
+
+        // ===== Only one approver signs: rejected =====
+        let one_signer_instruction = Instruction::new_with_bytes(
+            program_id,
+            &multisig_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new_readonly(first_approver.pubkey(), true),
+                AccountMeta::new_readonly(second_approver.pubkey(), false),
+            ],
+        );
+        let message = Message::new(&[one_signer_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &first_approver], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Increment with a single approver should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+        );
+
+        // ===== Both approvers sign: incremented =====
+        let two_signer_instruction = Instruction::new_with_bytes(
+            program_id,
+            &multisig_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new_readonly(first_approver.pubkey(), true),
+                AccountMeta::new_readonly(second_approver.pubkey(), true),
+            ],
+        );
+        let message = Message::new(&[two_signer_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(
+            &[&payer, &first_approver, &second_approver],
+            message,
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Multisig increment should succeed: {:?}",
+            result
+        );
+
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), initial_value + 1);
+    }
+
+    #[test]
+    fn test_set_step() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // ===== Initialize Counter at 0 =====
+        let counter_keypair = Keypair::new();
+        let init_instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 })
+                .expect("Failed to serialize instruction");
+
+        let initialize_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_instruction_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+
+        // ===== Set the step to 7 =====
+        let set_step_data = borsh::to_vec(&CounterInstruction::SetStep { step: 7 })
+            .expect("Failed to serialize instruction");
+        let set_step_instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_step_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+
+        let message = Message::new(
+            &[initialize_instruction, set_step_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Init + SetStep should succeed: {:?}",
+            result
+        );
+
+        // ===== Increment twice =====
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(
+            &[increment_instruction.clone(), increment_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(result.is_ok(), "Increments should succeed: {:?}", result);
+
+        let account = svm
+            .get_account(&counter_keypair.pubkey())
+            .expect("Account should exist");
+        let counter = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(counter.step, 7);
+        assert_eq!(
+            counter.count, 14,
+            "Two increments of 7 should advance by 14"
+        );
+
+        // ===== Anyone but the authority is rejected =====
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+        let set_step_data = borsh::to_vec(&CounterInstruction::SetStep { step: u64::MAX })
+            .expect("Failed to serialize instruction");
+        let set_step_instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_step_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(intruder.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[set_step_instruction], Some(&intruder.pubkey()));
+        let transaction = Transaction::new(&[&intruder], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Non-authority SetStep should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+        assert_eq!(read_counter(&svm, &counter_keypair.pubkey()).step, 7);
+    }
+
+    #[test]
+    fn test_legacy_counter_migrates_on_increment() {
+        use solana_sdk::account::Account;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Plant a counter with the original 8-byte layout (just `count`)
+        let legacy_keypair = Keypair::new();
+        let legacy_counter = legacy_keypair.pubkey();
+        svm.set_account(
+            legacy_counter,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(CounterAccount::LEGACY_LEN),
+                data: 5u64.to_le_bytes().to_vec(),
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(legacy_counter, false)],
+        );
+
+        // ===== Legacy counters still increment by 1 without migrating =====
+        let message = Message::new(
+            std::slice::from_ref(&increment_instruction),
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Legacy increment should succeed: {:?}",
+            result
+        );
+
+        let account = svm.get_account(&legacy_counter).unwrap();
+        assert_eq!(account.data().len(), CounterAccount::LEGACY_LEN);
+        assert_eq!(u64::from_le_bytes(account.data().try_into().unwrap()), 6);
+
+        // ===== SetStep needs the authority, which 8-byte counters predate =====
+        let set_step_data = borsh::to_vec(&CounterInstruction::SetStep { step: 3 })
+            .expect("Failed to serialize instruction");
+        let set_step_instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_step_data,
+            vec![
+                AccountMeta::new(legacy_counter, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[set_step_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("SetStep on an authority-less counter should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+
+        // ===== IncrementCounter with a payer grows it to the current layout =====
+        let migrate_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![
+                AccountMeta::new(legacy_counter, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[migrate_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Migrating increment should succeed: {:?}",
+            result
+        );
+
+        let account = svm.get_account(&legacy_counter).unwrap();
+        assert_eq!(account.data().len(), CounterAccount::LEN);
+        assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(CounterAccount::LEN));
+
+        let counter = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(counter.step, 0);
+        assert_eq!(counter.count, 7);
+
+        // ===== Migrating doesn't hand the counter to whoever paid for it =====
+        assert_eq!(counter.authority.to_bytes(), [0; 32]);
+
+        // ===== Once its creator claims it, the migrated counter can be closed =====
+        let claim_data = borsh::to_vec(&CounterInstruction::ClaimAuthority)
+            .expect("Failed to serialize instruction");
+        let claim_instruction = Instruction::new_with_bytes(
+            program_id,
+            &claim_data,
+            vec![
+                AccountMeta::new(legacy_counter, true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let recipient = Pubkey::new_unique();
+        let close_data = borsh::to_vec(&CounterInstruction::CloseCounter)
+            .expect("Failed to serialize instruction");
+        let close_instruction = Instruction::new_with_bytes(
+            program_id,
+            &close_data,
+            vec![
+                AccountMeta::new(legacy_counter, false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new(recipient, false),
+            ],
+        );
+        let rent = account.lamports;
+        let message = Message::new(
+            &[claim_instruction, close_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction =
+            Transaction::new(&[&payer, &legacy_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Claiming and closing should succeed: {:?}",
+            result
+        );
+
+        assert!(svm
+            .get_account(&legacy_counter)
+            .is_none_or(|account| account.lamports == 0));
+        assert_eq!(svm.get_account(&recipient).unwrap().lamports, rent);
+    }
+
+    #[test]
+    fn test_decrement_saturating_at_zero() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // ===== Initialize Counter at 0 =====
+        let counter_keypair = Keypair::new();
+        let init_instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 })
+                .expect("Failed to serialize instruction");
+
+        let initialize_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_instruction_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[initialize_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        // ===== Decrement a zero counter =====
+        let decrement_data = borsh::to_vec(&CounterInstruction::DecrementCounterSaturating)
+            .expect("Failed to serialize instruction");
+        let decrement_instruction = Instruction::new_with_bytes(
+            program_id,
+            &decrement_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[decrement_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Saturating decrement of zero should succeed: {:?}",
+            result
+        );
+
+        // Stays at zero
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_increment_returns_new_count() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // ===== Initialize Counter =====
+        let counter_keypair = Keypair::new();
+        let initial_value = 41u64;
+        let init_instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value })
+                .expect("Failed to serialize instruction");
+
+        let initialize_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_instruction_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[initialize_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).unwrap();
+
+        // ===== Increment and read the return data =====
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let metadata = svm
+            .send_transaction(transaction)
+            .expect("Increment should succeed");
+
+        assert_eq!(metadata.return_data.program_id, program_id);
+        let returned = u64::from_le_bytes(
+            metadata
+                .return_data
+                .data
+                .as_slice()
+                .try_into()
+                .expect("Return data should be a u64"),
+        );
+        assert_eq!(returned, initial_value + 1);
+    }
+
+    #[test]
+    fn test_initialize_via_codama_client() {
+        // Create a new LiteSVM instance
+        let mut svm = LiteSVM::new();
+
+        // Create a keypair for the transaction payer
+        let payer = Keypair::new();
+
+        // The Codama CPI builders always target the program ID baked into the
+        // generated client, so deploy our program at that address
+        let program_id =
+            Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+
+        // Airdrop some SOL to the payer
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        // Deploy our program
+        svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
+            .unwrap();
+
+        // ===== Initialize through InitializeCounterCpiBuilder =====
+        let counter_keypair = Keypair::new();
+        let initial_value = 77u64;
+        let codama_init_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounterCodamaClient { initial_value })
+                .expect("Failed to serialize instruction");
+
+        let codama_init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &codama_init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(program_id, false),
+            ],
+        );
+        let message = Message::new(&[codama_init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Initialize via Codama client should succeed: {:?}",
+            result
+        );
+
+        // Read back the value written by the inner InitializeCounter
+        let account = svm
+            .get_account(&counter_keypair.pubkey())
+            .expect("Account should exist");
+        assert_eq!(account.owner, program_id);
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), initial_value);
+    }
+
+    #[test]
+    fn test_pda_counter_signed_codama_invoke() {
+        // Create a new LiteSVM instance
+        let mut svm = LiteSVM::new();
+
+        // Create a keypair for the transaction payer
+        let payer = Keypair::new();
+
+        // The Codama CPI builders always target the program ID baked into the
+        // generated client, so deploy our program at that address
+        let program_id =
+            Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+
+        // Airdrop some SOL to the payer
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        // Deploy our program
+        svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
+            .unwrap();
+
+        // ===== Initialize the PDA counter =====
+        let (counter_pda, _bump) =
+            Pubkey::find_program_address(&[b"counter", payer.pubkey().as_ref()], &program_id);
+        let initial_value = 5u64;
+        let init_pda_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounterPda { initial_value })
+                .expect("Failed to serialize instruction");
+
+        let init_pda_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_pda_data,
+            vec![
+                AccountMeta::new(counter_pda, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_pda_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "PDA initialize should succeed: {:?}",
+            result
+        );
+
+        // ===== Increment through the signed Codama CPI =====
+        let signed_cpi_data = borsh::to_vec(&CounterInstruction::IncrementPdaCounterCodamaClient)
+            .expect("Failed to serialize instruction");
+        let signed_cpi_instruction = Instruction::new_with_bytes(
+            program_id,
+            &signed_cpi_data,
+            vec![
+                AccountMeta::new(counter_pda, false),
+                AccountMeta::new_readonly(payer.pubkey(), false),
+                AccountMeta::new_readonly(program_id, false),
+            ],
+        );
+        let message = Message::new(&[signed_cpi_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Signed Codama CPI should succeed: {:?}",
+            result
+        );
+
+        assert_counter_eq(&svm, &counter_pda, initial_value + 1);
+    }
+
+    #[test]
+    fn test_codama_self_cpi_rejects_foreign_counter() {
+        // Create a new LiteSVM instance
+        let mut svm = LiteSVM::new();
+
+        // Create a keypair for the transaction payer
+        let payer = Keypair::new();
+
+        // Deploy at the program ID the Codama CPI builders target
+        let program_id =
+            Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes());
+
+        // Airdrop some SOL to the payer
+        svm.airdrop(&payer.pubkey(), 1_000_000_000).unwrap();
+
+        // Deploy our program
+        svm.add_program_from_file(program_id, "target/deploy/counter_program.so")
+            .unwrap();
+
+        // A system-owned account posing as the counter
+        let fake_counter = Keypair::new();
+        svm.airdrop(&fake_counter.pubkey(), 1_000_000_000).unwrap();
+
+        let codama_cpi_data = borsh::to_vec(&CounterInstruction::IncrementCounterCodamaClient)
+            .expect("Failed to serialize instruction");
+        let codama_cpi_instruction = Instruction::new_with_bytes(
+            program_id,
+            &codama_cpi_data,
+            vec![
+                AccountMeta::new(fake_counter.pubkey(), false),
+                AccountMeta::new_readonly(program_id, false),
+            ],
+        );
+
+        let message = Message::new(&[codama_cpi_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+
+        let err = result.expect_err("Foreign counter account should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::IncorrectProgramId)
+        );
+    }
+
+    #[test]
+    fn test_big_counter_increments_past_u64_max() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Create a keypair for the big counter account
+        let counter_keypair = Keypair::new();
+
+        // ===== Initialize just below the u64 limit =====
+        let initial_value = u64::MAX as u128;
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeBigCounter { initial_value })
+            .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Big counter init should succeed: {:?}",
+            result
+        );
+
+        let account = svm
+            .get_account(&counter_keypair.pubkey())
+            .expect("Failed to get big counter account");
+        assert_eq!(account.data().len(), BigCounterAccount::LEN);
+
+        // ===== Increment past u64::MAX =====
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementBigCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Big counter increment should succeed: {:?}",
+            result
+        );
+
+        let account = svm
+            .get_account(&counter_keypair.pubkey())
+            .expect("Failed to get big counter account");
+        let counter = BigCounterAccount::try_from_slice(account.data())
+            .expect("Failed to deserialize big counter data");
+        assert_eq!(counter.count, u64::MAX as u128 + 1);
+    }
+
+    #[test]
+    fn test_increment_records_last_updated() {
+        use solana_sdk::clock::Clock;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Initialize a fresh counter
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 })
+            .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Initialize should succeed");
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+
+        // ===== First increment stamps the current time =====
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = 1_700_000_000;
+        svm.set_sysvar(&clock);
+
+        let message = Message::new(
+            std::slice::from_ref(&increment_instruction),
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("First increment should succeed");
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        let first = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(first.last_updated, 1_700_000_000);
+
+        // ===== Advance the clock and increment again =====
+        clock.unix_timestamp += 60;
+        svm.set_sysvar(&clock);
+        svm.expire_blockhash();
+
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Second increment should succeed");
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        let second = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(second.count, 2);
+        assert_eq!(second.last_updated, first.last_updated + 60);
+    }
+
+    #[test]
+    fn test_increment_rate_limited() {
+        use solana_sdk::clock::Clock;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Initialize a fresh counter
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 })
+            .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Initialize should succeed");
+
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = 1_700_000_000;
+        svm.set_sysvar(&clock);
+
+        let rate_limited_data = borsh::to_vec(&CounterInstruction::IncrementCounterRateLimited {
+            min_interval_secs: 30,
+        })
+        .expect("Failed to serialize instruction");
+        let rate_limited_instruction = Instruction::new_with_bytes(
+            program_id,
+            &rate_limited_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+
+        // ===== First call succeeds and stamps the counter =====
+        let message = Message::new(
+            std::slice::from_ref(&rate_limited_instruction),
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("First rate-limited increment should succeed");
+
+        // ===== An immediate second call is rejected =====
+        svm.expire_blockhash();
+        let message = Message::new(
+            std::slice::from_ref(&rate_limited_instruction),
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Immediate second increment should be rate limited");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::RateLimited as u32)
+            )
+        );
+
+        // ===== After the interval passes, it succeeds again =====
+        clock.unix_timestamp += 30;
+        svm.set_sysvar(&clock);
+        svm.expire_blockhash();
+        let message = Message::new(&[rate_limited_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Increment after the interval should succeed");
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        let counter = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(counter.count, 2);
+        assert_eq!(counter.last_updated, 1_700_000_030);
+    }
+
+    #[test]
+    fn test_increment_respects_max_value() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Initialize a counter at 0 and cap it at 3
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 })
+            .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let set_max_data = borsh::to_vec(&CounterInstruction::SetMaxValue { max_value: 3 })
+            .expect("Failed to serialize instruction");
+        let set_max_instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_max_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(
+            &[init_instruction, set_max_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Initialize and SetMaxValue should succeed");
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+
+        // ===== Three increments reach the cap =====
+        let message = Message::new(
+            &[
+                increment_instruction.clone(),
+                increment_instruction.clone(),
+                increment_instruction.clone(),
+            ],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Increments up to the cap should succeed");
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        let counter = CounterAccount::load(account.data()).unwrap();
+        assert_eq!(counter.count, 3);
+        assert_eq!(counter.max_value, 3);
+
+        // ===== The fourth increment is rejected =====
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Increment past the cap should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CounterOverflow as u32)
+            )
+        );
+    }
+
+    #[test]
+    fn test_overflow_returns_documented_error_code() {
+        use solana_program::program_error::ProgramError;
+
+        // The conversion uses the explicit discriminant documented on CounterError
+        assert_eq!(
+            ProgramError::from(CounterError::CounterOverflow),
+            ProgramError::Custom(1)
+        );
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Start a big counter at its maximum so the next increment overflows
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeBigCounter {
+            initial_value: u128::MAX,
+        })
+        .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementBigCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+
+        let message = Message::new(
+            &[init_instruction, increment_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Increment past u128::MAX should fail");
+
+        // Native code 1, where the Anchor program would report 6000
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(1, InstructionError::Custom(1))
+        );
+    }
+
+    #[test]
+    fn test_program_keypair_path_fallback() {
+        // No override (or an empty one) falls back to the default location
+        assert_eq!(resolve_keypair_path(None), NATIVE_PROGRAM_KEYPAIR);
+        assert_eq!(
+            resolve_keypair_path(Some(String::new())),
+            NATIVE_PROGRAM_KEYPAIR
+        );
+
+        // An override wins
+        assert_eq!(
+            resolve_keypair_path(Some("/tmp/custom-keypair.json".to_string())),
+            "/tmp/custom-keypair.json"
+        );
+
+        // And the default location holds the deployed program keypair
+        let keypair = solana_sdk::signature::read_keypair_file(NATIVE_PROGRAM_KEYPAIR)
+            .expect("Default program keypair should be readable");
+        assert_ne!(keypair.pubkey(), Pubkey::default());
+    }
+
+    #[test]
+    fn test_unknown_opcode_is_rejected() {
+        // MAX_OPCODE must track the last variant
+        let last = borsh::to_vec(&CounterInstruction::AssertCount { expected: 27 }).unwrap();
+        assert_eq!(last[0], CounterInstruction::MAX_OPCODE);
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        // An opcode from some future version of the program
+        let unknown_instruction = Instruction::new_with_bytes(
+            program_id,
+            &[CounterInstruction::MAX_OPCODE + 1],
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[unknown_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Unknown opcode should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidInstructionData as u32)
+            )
+        );
+
+        // The counter is untouched
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_truncated_payload_is_rejected() {
+        let data = borsh::to_vec(&CounterInstruction::SetStep { step: 5 }).unwrap();
+
+        // A known opcode whose u64 argument is cut short
+        assert_eq!(
+            CounterInstruction::unpack(&data[..4]),
+            Err(CounterError::InvalidInstructionData.into())
+        );
+        assert_eq!(
+            CounterInstruction::unpack(&data),
+            Ok(CounterInstruction::SetStep { step: 5 })
+        );
+    }
+
+    #[test]
+    fn test_increment_and_transfer() {
+        use solana_program::{program_option::COption, program_pack::Pack};
+        use solana_sdk::account::Account;
+        use spl_token::state::{Account as TokenAccount, AccountState, Mint};
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 10);
+
+        // SPL Token types use the program-side Pubkey
+        let to_program_pubkey = |key: &Pubkey| ProgramPubkey::new_from_array(key.to_bytes());
+        let token_program_id = Pubkey::new_from_array(spl_token::ID.to_bytes());
+
+        // Plant a mint and two token accounts owned by the SPL Token program
+        let mut plant = |key: Pubkey, data: Vec<u8>| {
+            svm.set_account(
+                key,
+                Account {
+                    lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                    data,
+                    owner: token_program_id,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        };
+
+        let mint = Pubkey::new_unique();
+        let mut mint_data = vec![0u8; Mint::LEN];
+        Mint::pack(
+            Mint {
+                mint_authority: COption::None,
+                supply: 5,
+                decimals: 0,
+                is_initialized: true,
+                freeze_authority: COption::None,
+            },
+            &mut mint_data,
+        )
+        .unwrap();
+        plant(mint, mint_data);
+
+        let token_account = |owner: &Pubkey, amount: u64| {
+            let mut data = vec![0u8; TokenAccount::LEN];
+            TokenAccount::pack(
+                TokenAccount {
+                    mint: to_program_pubkey(&mint),
+                    owner: to_program_pubkey(owner),
+                    amount,
+                    state: AccountState::Initialized,
+                    ..TokenAccount::default()
+                },
+                &mut data,
+            )
+            .unwrap();
+            data
+        };
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let recipient = Pubkey::new_unique();
+        plant(source, token_account(&payer.pubkey(), 5));
+        plant(destination, token_account(&recipient, 0));
+
+        // ===== Increment and transfer in one instruction =====
+        let data = borsh::to_vec(&CounterInstruction::IncrementAndTransfer)
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(source, false),
+                AccountMeta::new(destination, false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new_readonly(token_program_id, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "IncrementAndTransfer should succeed: {:?}",
+            result
+        );
+
+        // Both the counter and the token balances moved
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 11);
+        let balance = |key: &Pubkey| {
+            let account = svm.get_account(key).expect("Token account should exist");
+            TokenAccount::unpack(account.data()).unwrap().amount
+        };
+        assert_eq!(balance(&source), 4);
+        assert_eq!(balance(&destination), 1);
+    }
+
+    #[test]
+    fn test_decrement_via_codama_client() {
+        let (mut svm, payer, program_id) = setup_codama_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 7);
+
+        // The client-side builder produces the native DecrementCounter bytes
+        let client_instruction = codama_client::instructions::DecrementCounterBuilder::new()
+            .counter(solana_pubkey::Pubkey::new_from_array(
+                counter_keypair.pubkey().to_bytes(),
+            ))
+            .instruction();
+        assert_eq!(
+            client_instruction.data,
+            borsh::to_vec(&CounterInstruction::DecrementCounter).unwrap()
+        );
+
+        // ===== Decrement through the Codama CPI builder =====
+        let data = borsh::to_vec(&CounterInstruction::DecrementCounterCodamaClient)
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new_readonly(program_id, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Codama decrement should succeed: {:?}",
+            result
+        );
+
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 6);
+    }
+
+    #[test]
+    fn test_codama_initialize_builder_matches_borsh() {
+        let counter = solana_pubkey::Pubkey::new_unique();
+        let payer = solana_pubkey::Pubkey::new_unique();
+
+        for initial_value in [0u64, 1, 42, u64::MAX] {
+            let instruction = codama_client::instructions::InitializeCounterBuilder::new()
+                .counter(counter)
+                .payer(payer)
+                .initial_value(initial_value)
+                .instruction();
+
+            // Same bytes as the manual path: discriminator 0 followed by the u64 arg
+            assert_eq!(
+                instruction.data,
+                borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value }).unwrap()
+            );
+
+            // Accounts follow the declared order, with the System Program defaulted
+            let keys: Vec<_> = instruction
+                .accounts
+                .iter()
+                .map(|meta| meta.pubkey)
+                .collect();
+            assert_eq!(keys[..2], [counter, payer]);
+            assert_eq!(keys[2].to_bytes(), SYSTEM_PROGRAM_ID.to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_codama_increment_builder_matches_borsh() {
+        use codama_client::instructions::{
+            IncrementCounterBuilder, INCREMENT_COUNTER_DISCRIMINATOR,
+            INITIALIZE_COUNTER_DISCRIMINATOR,
+        };
+
+        let counter = solana_pubkey::Pubkey::new_unique();
+        let instruction = IncrementCounterBuilder::new()
+            .counter(counter)
+            .instruction();
+
+        // The generated client encodes the same bytes the program decodes
+        let native = borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap();
+        assert_eq!(instruction.data, native);
+        assert_eq!(instruction.data, [INCREMENT_COUNTER_DISCRIMINATOR]);
+        assert_eq!(
+            instruction.program_id.to_bytes(),
+            codama_program_id().to_bytes()
+        );
+        assert_eq!(instruction.accounts.len(), 4);
+        assert_eq!(instruction.accounts[0].pubkey, counter);
+        assert!(instruction.accounts[0].is_writable);
+
+        // Unset optional accounts are filled with the program ID, which the program skips
+        for placeholder in &instruction.accounts[1..] {
+            assert_eq!(
+                placeholder.pubkey,
+                codama_client::programs::COUNTER_PROGRAM_ID
+            );
+            assert!(!placeholder.is_writable && !placeholder.is_signer);
+        }
+
+        // The generated discriminators are the native opcodes
+        let initialize =
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 0 }).unwrap();
+        assert_eq!(initialize[0], INITIALIZE_COUNTER_DISCRIMINATOR);
+    }
+
+    #[test]
+    fn test_history_counter_keeps_last_eight_values() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Initialize a history counter at 0
+        let counter_keypair = Keypair::new();
+        let init_data =
+            borsh::to_vec(&CounterInstruction::InitializeHistoryCounter { initial_value: 0 })
+                .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("History counter init should succeed");
+
+        // ===== Increment ten times =====
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementHistoryCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&vec![increment_instruction; 10], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("History increments should succeed");
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        assert_eq!(account.data().len(), HistoryCounterAccount::LEN);
+        let counter = HistoryCounterAccount::try_from_slice(account.data()).unwrap();
+
+        // The ring holds values 3..=10, oldest first
+        assert_eq!(counter.count, 10);
+        assert_eq!(counter.history_in_order(), [3, 4, 5, 6, 7, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_increment_rejects_extra_account() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        // One account too many for IncrementCounter (counter, payer, system program, stats)
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let mut accounts = vec![AccountMeta::new(counter_keypair.pubkey(), false)];
+        accounts.extend((0..4).map(|_| AccountMeta::new_readonly(Pubkey::new_unique(), false)));
+        let increment_instruction =
+            Instruction::new_with_bytes(program_id, &increment_data, accounts);
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Extra account should be rejected");
+
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::UnexpectedAccountCount as u32)
+            )
+        );
+        assert!(err
+            .meta
+            .logs
+            .iter()
+            .any(|log| log.contains("Expected 4 accounts, got 5")));
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_increment_rejects_payer_without_system_program() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        // A lone payer sits in the payer slot, not the stats slot
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+            ],
+        );
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Payer without System Program should be rejected");
+
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::MissingAccount as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_find_counter_pda_matches_on_chain_init() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        assert_eq!(program_id.to_bytes(), crate::ID.to_bytes());
+
+        // Derive the address client-side
+        let (counter_pda, _bump) =
+            find_counter_pda(&ProgramPubkey::new_from_array(payer.pubkey().to_bytes()));
+        let counter_pda = Pubkey::new_from_array(counter_pda.to_bytes());
+
+        // The program accepts it as the PDA and creates the account there
+        let init_pda_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounterPda { initial_value: 3 })
+                .expect("Failed to serialize instruction");
+        let init_pda_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_pda_data,
+            vec![
+                AccountMeta::new(counter_pda, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_pda_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "PDA initialize should succeed: {:?}",
+            result
+        );
+
+        let account = svm
+            .get_account(&counter_pda)
+            .expect("Counter should exist at the derived PDA");
+        assert_eq!(account.owner, program_id);
+        assert_counter_eq(&svm, &counter_pda, 3);
+    }
+
+    #[test]
+    fn test_transfer_authority() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 5);
+        let new_authority = Keypair::new();
+        svm.airdrop(&new_authority.pubkey(), 1_000_000_000).unwrap();
+
+        // The initializing payer starts out as the authority
+        let counter = read_counter(&svm, &counter_keypair.pubkey());
+        assert_eq!(counter.authority.to_bytes(), payer.pubkey().to_bytes());
+
+        let reset_data = borsh::to_vec(&CounterInstruction::ResetCounter)
+            .expect("Failed to serialize instruction");
+        let reset_by = |authority: &Keypair| {
+            Instruction::new_with_bytes(
+                program_id,
+                &reset_data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), false),
+                    AccountMeta::new_readonly(authority.pubkey(), true),
+                ],
+            )
+        };
+
+        // ===== Hand authority to the new key =====
+        let transfer_data = borsh::to_vec(&CounterInstruction::TransferAuthority {
+            new_authority: ProgramPubkey::new_from_array(new_authority.pubkey().to_bytes()),
+        })
+        .expect("Failed to serialize instruction");
+        let transfer_instruction = Instruction::new_with_bytes(
+            program_id,
+            &transfer_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
+        );
+        let message = Message::new(&[transfer_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Transfer by the current authority should succeed");
+
+        let counter = read_counter(&svm, &counter_keypair.pubkey());
+        assert_eq!(
+            counter.authority.to_bytes(),
+            new_authority.pubkey().to_bytes()
+        );
+
+        // ===== The old authority can no longer reset =====
+        let message = Message::new(&[reset_by(&payer)], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Old authority should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 5);
+
+        // ===== The new authority can =====
+        let message = Message::new(&[reset_by(&new_authority)], Some(&new_authority.pubkey()));
+        let transaction = Transaction::new(&[&new_authority], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("New authority should be able to reset");
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_initialize_rejects_counter_equal_to_payer() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Pass the payer as the counter account too
+        let init_instruction = initialize_ix(program_id, &payer.pubkey(), &payer.pubkey(), 1);
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Counter equal to payer should be rejected");
+
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CounterIsPayer as u32)
+            )
+        );
+        let payer_account = svm.get_account(&payer.pubkey()).unwrap();
+        assert_eq!(payer_account.owner, SYSTEM_PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_get_count_returns_count() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 77);
+
+        // The counter is passed read-only
+        let get_count_data =
+            borsh::to_vec(&CounterInstruction::GetCount).expect("Failed to serialize instruction");
+        let get_count_instruction = Instruction::new_with_bytes(
+            program_id,
+            &get_count_data,
+            vec![AccountMeta::new_readonly(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[get_count_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let metadata = svm
+            .send_transaction(transaction)
+            .expect("GetCount should succeed");
+
+        assert_eq!(metadata.return_data.program_id, program_id);
+        let returned = u64::from_le_bytes(
+            metadata
+                .return_data
+                .data
+                .as_slice()
+                .try_into()
+                .expect("Return data should be a u64"),
+        );
+        assert_eq!(returned, 77);
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 77);
+    }
+
+    #[test]
+    fn test_increment_saturating_at_max() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, u64::MAX);
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounterSaturating)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter.pubkey(), false)],
+        );
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Saturating increment at u64::MAX should succeed: {:?}",
+            result
+        );
+
+        // Pinned at u64::MAX
+        assert_counter_eq(&svm, &counter.pubkey(), u64::MAX);
+    }
+
+    #[test]
+    fn test_toggle_enabled_pauses_increment() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 10);
+        assert!(read_counter(&svm, &counter.pubkey()).enabled);
+
+        let toggle = |svm: &mut LiteSVM| {
+            let data = borsh::to_vec(&CounterInstruction::ToggleEnabled)
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter.pubkey(), false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect("Toggle transaction should succeed");
+            svm.expire_blockhash();
+        };
+        let increment = |svm: &mut LiteSVM| {
+            let data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![AccountMeta::new(counter.pubkey(), false)],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let result = svm.send_transaction(transaction);
+            svm.expire_blockhash();
+            result.map(|_| ()).map_err(|failed| failed.err)
+        };
+
+        // ===== Disabled: increment fails =====
+        toggle(&mut svm);
+        assert!(!read_counter(&svm, &counter.pubkey()).enabled);
+
+        let err = increment(&mut svm).expect_err("Increment of a disabled counter should fail");
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CounterDisabled as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter.pubkey(), 10);
+
+        // ===== Re-enabled: increment succeeds =====
+        toggle(&mut svm);
+        assert!(read_counter(&svm, &counter.pubkey()).enabled);
+
+        increment(&mut svm).expect("Increment of a re-enabled counter should succeed");
+        assert_counter_eq(&svm, &counter.pubkey(), 11);
+    }
+
+    #[test]
+    fn test_paused_counter_rejects_every_count_change() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 10);
+        let source = init_counter(&mut svm, &payer, program_id, 3);
+
+        let send =
+            |svm: &mut LiteSVM, instruction: CounterInstruction, accounts: Vec<AccountMeta>| {
+                let instruction = Instruction::new_with_bytes(
+                    program_id,
+                    &borsh::to_vec(&instruction).expect("Failed to serialize instruction"),
+                    accounts,
+                );
+                let message = Message::new(&[instruction], Some(&payer.pubkey()));
+                let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+                let result = svm.send_transaction(transaction);
+                svm.expire_blockhash();
+                result.map(|_| ()).map_err(|failed| failed.err)
+            };
+        let counter_meta = AccountMeta::new(counter.pubkey(), false);
+        let authority_meta = AccountMeta::new_readonly(payer.pubkey(), true);
+
+        // Take a snapshot first so RestoreCounter has something to roll back to
+        send(
+            &mut svm,
+            CounterInstruction::SnapshotCounter,
+            vec![
+                counter_meta.clone(),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        )
+        .expect("Snapshot should succeed");
+        send(
+            &mut svm,
+            CounterInstruction::ToggleEnabled,
+            vec![
+                counter_meta.clone(),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        )
+        .expect("Pause should succeed");
+
+        let attempts = [
+            (
+                CounterInstruction::IncrementCounterSaturating,
+                vec![counter_meta.clone()],
+            ),
+            (
+                CounterInstruction::DecrementCounter,
+                vec![counter_meta.clone()],
+            ),
+            (
+                CounterInstruction::DecrementCounterSaturating,
+                vec![counter_meta.clone()],
+            ),
+            (
+                CounterInstruction::AdjustCounter { delta: -10 },
+                vec![counter_meta.clone(), authority_meta.clone()],
+            ),
+            (
+                CounterInstruction::CopyCounter,
+                vec![
+                    AccountMeta::new_readonly(source.pubkey(), false),
+                    counter_meta.clone(),
+                    authority_meta.clone(),
+                ],
+            ),
+            (
+                CounterInstruction::RestoreCounter,
+                vec![counter_meta.clone(), authority_meta.clone()],
+            ),
+            (
+                CounterInstruction::ResetCounter,
+                vec![counter_meta.clone(), authority_meta.clone()],
+            ),
+        ];
+        for (instruction, accounts) in attempts {
+            let name = format!("{:?}", instruction);
+            assert_eq!(
+                send(&mut svm, instruction, accounts),
+                Err(TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(CounterError::CounterDisabled as u32)
+                )),
+                "{} should fail on a paused counter",
+                name
+            );
+        }
+        assert_counter_eq(&svm, &counter.pubkey(), 10);
+    }
+
+    #[test]
+    fn test_toggle_enabled_requires_authority() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 3);
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+
+        let toggle_data = borsh::to_vec(&CounterInstruction::ToggleEnabled)
+            .expect("Failed to serialize instruction");
+        let toggle_by = |authority: &Keypair| {
+            Instruction::new_with_bytes(
+                program_id,
+                &toggle_data,
+                vec![
+                    AccountMeta::new(counter.pubkey(), false),
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            )
+        };
+
+        // ===== A non-authority cannot pause the counter =====
+        let message = Message::new(&[toggle_by(&intruder)], Some(&intruder.pubkey()));
+        let transaction = Transaction::new(&[&intruder], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Non-authority toggle should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+        assert!(read_counter(&svm, &counter.pubkey()).enabled);
+
+        // ===== The authority can =====
+        let message = Message::new(&[toggle_by(&payer)], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Authority toggle should succeed");
+        assert!(!read_counter(&svm, &counter.pubkey()).enabled);
+    }
+
+    #[test]
+    fn test_named_counter_round_trips_name() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let counter_keypair = Keypair::new();
+        let name = "page views".to_string();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeNamedCounter {
+            initial_value: 12,
+            name: name.clone(),
+        })
+        .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Named counter init should succeed");
+
+        // Sized to the name: 8-byte count + 4-byte length prefix + name bytes
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        assert_eq!(account.data().len(), NamedCounterAccount::space(name.len()));
+        assert_eq!(
+            u32::from_le_bytes(account.data()[8..12].try_into().unwrap()) as usize,
+            name.len()
+        );
+
+        let counter = NamedCounterAccount::try_from_slice(account.data())
+            .expect("Failed to deserialize named counter");
+        assert_eq!(counter.count, 12);
+        assert_eq!(counter.name, name);
+    }
+
+    #[test]
+    fn test_cpi_to_anchor_counter_forwards_authority_signer() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+        let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id, 7);
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+
+        let cpi_data = borsh::to_vec(&CounterInstruction::IncrementAnchorCounter)
+            .expect("Failed to serialize instruction");
+        let increment_via_cpi = |authority: &Keypair, is_signer: bool| {
+            Instruction::new_with_bytes(
+                native_program_id,
+                &cpi_data,
+                vec![
+                    AccountMeta::new(anchor_counter.pubkey(), false),
+                    AccountMeta::new_readonly(authority.pubkey(), is_signer),
+                    AccountMeta::new_readonly(anchor_program_id, false),
+                ],
+            )
+        };
+
+        // ===== A signer that isn't the stored authority fails inside Anchor =====
+        // `has_one = authority` reports ConstraintHasOne (2001)
+        let message = Message::new(&[increment_via_cpi(&intruder, true)], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer, &intruder], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("CPI with a mismatched authority should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::Custom(2001))
+        );
+
+        // ===== The right key without its signature fails too =====
+        // The native program can only forward signatures it received; Anchor's
+        // `Signer` check reports AccountNotSigner (3010)
+        let message = Message::new(&[increment_via_cpi(&payer, false)], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("CPI without the authority's signature should fail");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::Custom(3010))
+        );
+        assert_eq!(read_anchor_count(&svm, &anchor_counter.pubkey()), 7);
+
+        // ===== The stored authority, signing, is forwarded and accepted =====
+        let message = Message::new(&[increment_via_cpi(&payer, true)], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("CPI with the authority signer should succeed");
+        assert_eq!(read_anchor_count(&svm, &anchor_counter.pubkey()), 8);
+    }
+
+    // One instance per variant, in declaration order
+    fn one_of_each_instruction() -> Vec<CounterInstruction> {
+        vec![
+            CounterInstruction::InitializeCounter {
+                initial_value: 0x0102_0304_0506_0708,
+            },
+            CounterInstruction::IncrementCounter,
+            CounterInstruction::IncrementAnchorCounter,
+            CounterInstruction::IncrementAnchorCounterRaw,
+            CounterInstruction::IncrementCounterSelfCpi,
+            CounterInstruction::IncrementCounterCodamaClient,
+            CounterInstruction::IncrementCounterMultisig,
+            CounterInstruction::SetStep { step: 3 },
+            CounterInstruction::DecrementCounterSaturating,
+            CounterInstruction::InitializeCounterCodamaClient { initial_value: 4 },
+            CounterInstruction::InitializeCounterPda { initial_value: 5 },
+            CounterInstruction::IncrementPdaCounterCodamaClient,
+            CounterInstruction::InitializeBigCounter {
+                initial_value: u128::MAX - 1,
+            },
+            CounterInstruction::IncrementBigCounter,
+            CounterInstruction::IncrementCounterRateLimited {
+                min_interval_secs: -60,
+            },
+            CounterInstruction::SetMaxValue { max_value: 1_000 },
+            CounterInstruction::DecrementCounter,
+            CounterInstruction::IncrementAndTransfer,
+            CounterInstruction::DecrementCounterCodamaClient,
+            CounterInstruction::InitializeHistoryCounter { initial_value: 6 },
+            CounterInstruction::IncrementHistoryCounter,
+            CounterInstruction::ResetCounter,
+            CounterInstruction::TransferAuthority {
+                new_authority: ProgramPubkey::new_from_array([7; 32]),
+            },
+            CounterInstruction::GetCount,
+            CounterInstruction::IncrementCounterSaturating,
+            CounterInstruction::ToggleEnabled,
+            CounterInstruction::InitializeNamedCounter {
+                initial_value: 8,
+                name: "named".to_string(),
+            },
+            CounterInstruction::InitializeCounterIndexed {
+                index: 9,
+                initial_value: 10,
+            },
+            CounterInstruction::BatchIncrement,
+            CounterInstruction::SnapshotCounter,
+            CounterInstruction::RestoreCounter,
+            CounterInstruction::QueryRent,
+            CounterInstruction::LogMemo {
+                message: "hello".to_string(),
+            },
+            CounterInstruction::CompareAndIncrement { expected: 11 },
+            CounterInstruction::CloseCounter,
+            CounterInstruction::InitializeCounterIdempotent { initial_value: 12 },
+            CounterInstruction::SetDelegate { delegate: None },
+            CounterInstruction::IncrementAsDelegate,
+            CounterInstruction::WithdrawSurplus,
+            CounterInstruction::InitializeWhitelistCounter {
+                initial_value: 13,
+                merkle_root: [14; 32],
+            },
+            CounterInstruction::IncrementIfWhitelisted {
+                proof: vec![[15; 32]],
+            },
+            CounterInstruction::CommitIncrement { hash: [17; 32] },
+            CounterInstruction::RevealIncrement { nonce: 18 },
+            CounterInstruction::RecordEpoch,
+            CounterInstruction::InitializeCounterWithSize {
+                initial_value: 19,
+                reserved: 20,
+            },
+            CounterInstruction::AppendLog { entry: 21 },
+            CounterInstruction::InitializeCounterWithOverflowBehavior {
+                initial_value: 22,
+                overflow_behavior: 2,
+            },
+            CounterInstruction::AdjustCounter { delta: -23 },
+            CounterInstruction::InitAndIncrementSelfCpi { initial_value: 24 },
+            CounterInstruction::InitializeStats,
+            CounterInstruction::InitializeCountersBatch {
+                count: 3,
+                initial_value: 25,
+            },
+            CounterInstruction::IncrementCounterWithNonce { nonce: 26 },
+            CounterInstruction::CopyCounter,
+            CounterInstruction::AssertCount { expected: 27 },
+            CounterInstruction::ClaimAuthority,
+        ]
+    }
+
+    #[test]
+    fn test_every_instruction_round_trips() {
+        let instructions = one_of_each_instruction();
+        assert_eq!(
+            instructions.len(),
+            CounterInstruction::MAX_OPCODE as usize + 1,
+            "Every variant should be covered"
+        );
+
+        for (opcode, instruction) in instructions.iter().enumerate() {
+            let data = borsh::to_vec(instruction).expect("Failed to serialize instruction");
+
+            // Reordering variants would silently change these
+            assert_eq!(data[0] as usize, opcode, "Opcode of {:?}", instruction);
+
+            let decoded = CounterInstruction::try_from_slice(&data)
+                .expect("Failed to deserialize instruction");
+            assert_eq!(&decoded, instruction);
+        }
+    }
+
+    #[test]
+    fn test_dispatch_table_covers_every_instruction() {
+        use solana_program::program_error::ProgramError;
+
+        let instructions = one_of_each_instruction();
+        assert_eq!(processor::HANDLERS.len(), instructions.len());
+
+        let program_id = ProgramPubkey::new_unique();
+        for (opcode, instruction) in instructions.iter().enumerate() {
+            let handler = processor::HANDLERS[opcode];
+
+            // Without accounts every handler fails, but not because it expected another variant
+            assert_ne!(
+                handler(&program_id, &[], instruction.clone()),
+                Err(ProgramError::InvalidInstructionData),
+                "Opcode {} is not handled by its own handler",
+                opcode
+            );
+
+            let other = &instructions[(opcode + 1) % instructions.len()];
+            assert_eq!(
+                handler(&program_id, &[], other.clone()),
+                Err(ProgramError::InvalidInstructionData),
+                "Handler for opcode {} accepted {:?}",
+                opcode,
+                other
+            );
+        }
+    }
+
+    #[test]
+    fn test_instruction_and_account_compare_and_clone() {
+        let set_step = CounterInstruction::SetStep { step: 5 };
+        assert_eq!(set_step, CounterInstruction::SetStep { step: 5 });
+        assert_ne!(set_step, CounterInstruction::SetStep { step: 6 });
+        assert_eq!(set_step.clone(), set_step);
+
+        // A clone is an independent copy
+        let original = CounterAccount {
+            count: 10,
+            ..CounterAccount::default()
+        };
+        let mut copy = original.clone();
+        assert_eq!(copy, original);
+        copy.count += 1;
+        assert_eq!(original.count, 10);
+        assert_ne!(copy, original);
+    }
+
+    #[test]
+    fn test_anchor_initialize_ix_matches_hand_built_bytes() {
+        let counter = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let anchor_program_id = Pubkey::new_unique();
+
+        let instruction = anchor_initialize_ix(counter, payer, 0x0102_0304, anchor_program_id);
+
+        // 8-byte discriminator, then the u64 argument in little-endian
+        let expected = [
+            67, 89, 100, 87, 231, 172, 35, 124, // initialize_counter
+            4, 3, 2, 1, 0, 0, 0, 0, // initial_value
+        ];
+        assert_eq!(instruction.data, expected);
+        assert_eq!(instruction.program_id, anchor_program_id);
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(counter, true),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_indexed_counters_are_independent() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let payer_key = ProgramPubkey::new_from_array(payer.pubkey().to_bytes());
+
+        let mut counters = Vec::new();
+        for (index, initial_value) in [(0u64, 10u64), (1, 20)] {
+            let (counter_pda, bump) = find_indexed_counter_pda(&payer_key, index);
+            let counter_pda = Pubkey::new_from_array(counter_pda.to_bytes());
+
+            let init_data = borsh::to_vec(&CounterInstruction::InitializeCounterIndexed {
+                index,
+                initial_value,
+            })
+            .expect("Failed to serialize instruction");
+            let init_instruction = Instruction::new_with_bytes(
+                program_id,
+                &init_data,
+                vec![
+                    AccountMeta::new(counter_pda, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect("Indexed counter init should succeed");
+
+            let account = svm.get_account(&counter_pda).unwrap();
+            assert_eq!(account.data().len(), IndexedCounterAccount::LEN);
+            let counter = IndexedCounterAccount::try_from_slice(account.data()).unwrap();
+            assert_eq!(counter.count, initial_value);
+            assert_eq!(counter.index, index);
+            assert_eq!(counter.bump, bump);
+
+            counters.push(counter_pda);
+        }
+
+        // Same payer, different index: distinct addresses
+        assert_ne!(counters[0], counters[1]);
+    }
+
+    #[test]
+    fn test_native_and_anchor_counters_agree() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+
+        // ===== Same sequence on both: init 10, +1, +1 =====
+        let native_counter = init_counter(&mut svm, &payer, native_program_id, 10);
+        let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id, 10);
+
+        let native_increment = Instruction::new_with_bytes(
+            native_program_id,
+            &borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap(),
+            vec![AccountMeta::new(native_counter.pubkey(), false)],
+        );
+        let anchor_increment = Instruction::new_with_bytes(
+            anchor_program_id,
+            &AnchorIx::IncrementCounter.to_data(),
+            vec![
+                AccountMeta::new(anchor_counter.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
+        );
+
+        let message = Message::new(
+            &[
+                native_increment.clone(),
+                anchor_increment.clone(),
+                native_increment,
+                anchor_increment,
+            ],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Increments on both programs should succeed");
+
+        // ===== Each decoded its own way: borsh vs. 8-byte Anchor discriminator =====
+        let native_count = read_counter(&svm, &native_counter.pubkey()).count;
+        let anchor_count = read_anchor_count(&svm, &anchor_counter.pubkey());
+        assert_eq!(native_count, 12);
+        assert_eq!(anchor_count, 12);
+    }
+
+    #[test]
+    fn test_anchor_cpi_rejects_non_executable_program() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+        let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id, 1);
+
+        // A plain wallet posing as the Anchor program, through both CPI paths
+        for instruction in [
+            CounterInstruction::IncrementAnchorCounter,
+            CounterInstruction::IncrementAnchorCounterRaw,
+        ] {
+            let cpi_instruction = Instruction::new_with_bytes(
+                native_program_id,
+                &borsh::to_vec(&instruction).unwrap(),
+                vec![
+                    AccountMeta::new(anchor_counter.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                    AccountMeta::new_readonly(Keypair::new().pubkey(), false),
+                ],
+            );
+            let message = Message::new(&[cpi_instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let err = svm
+                .send_transaction(transaction)
+                .expect_err("CPI into a non-executable account should fail");
+            assert_eq!(
+                err.err,
+                TransactionError::InstructionError(
+                    0,
+                    InstructionError::Custom(CounterError::ProgramNotExecutable as u32)
+                ),
+                "{:?}",
+                instruction
+            );
+        }
+
+        assert_eq!(read_anchor_count(&svm, &anchor_counter.pubkey()), 1);
+    }
+
+    #[test]
+    fn test_batch_increment_stops_early_on_tight_budget() {
+        const COUNTERS: usize = 20;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counters: Vec<Keypair> = (0..COUNTERS)
+            .map(|_| init_counter(&mut svm, &payer, program_id, 0))
+            .collect();
+
+        let batch_data = borsh::to_vec(&CounterInstruction::BatchIncrement)
+            .expect("Failed to serialize instruction");
+        let batch_instruction = Instruction::new_with_bytes(
+            program_id,
+            &batch_data,
+            counters
+                .iter()
+                .map(|counter| AccountMeta::new(counter.pubkey(), false))
+                .collect(),
+        );
+        let mut run_batch = |compute_unit_limit: u32| {
+            let message = Message::new(
+                &[
+                    set_compute_unit_limit_ix(compute_unit_limit),
+                    batch_instruction.clone(),
+                ],
+                Some(&payer.pubkey()),
+            );
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let metadata = svm
+                .send_transaction(transaction)
+                .expect("Batch increment should succeed, even when cut short");
+            svm.expire_blockhash();
+            u64::from_le_bytes(
+                metadata
+                    .return_data
+                    .data
+                    .as_slice()
+                    .try_into()
+                    .expect("Return data should be a u64"),
+            ) as usize
+        };
+
+        // ===== Plenty of budget: every counter is incremented =====
+        assert_eq!(run_batch(1_400_000), COUNTERS);
+
+        // ===== Tight budget: a partial count comes back instead of a failure =====
+        let completed = run_batch(12_000);
+        assert!(
+            completed > 0 && completed < COUNTERS,
+            "Expected a partial batch, got {} of {}",
+            completed,
+            COUNTERS
+        );
+
+        // The first `completed` counters moved twice, the rest only once
+        for (i, counter) in counters.iter().enumerate() {
+            let expected = if i < completed { 2 } else { 1 };
+            assert_counter_eq(&svm, &counter.pubkey(), expected);
+        }
+    }
+
+    #[test]
+    fn test_snapshot_and_restore() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 0);
+
+        let instruction = |instruction: CounterInstruction, accounts: Vec<AccountMeta>| {
+            Instruction::new_with_bytes(
+                program_id,
+                &borsh::to_vec(&instruction).expect("Failed to serialize instruction"),
+                accounts,
+            )
+        };
+        let increment = instruction(
+            CounterInstruction::IncrementCounter,
+            vec![AccountMeta::new(counter.pubkey(), false)],
+        );
+        let snapshot = instruction(
+            CounterInstruction::SnapshotCounter,
+            vec![
+                AccountMeta::new(counter.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let restore = instruction(
+            CounterInstruction::RestoreCounter,
+            vec![
+                AccountMeta::new(counter.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
+        );
+
+        // ===== +2, snapshot at 2, +3 =====
+        let message = Message::new(
+            &[
+                increment.clone(),
+                increment.clone(),
+                snapshot,
+                increment.clone(),
+                increment.clone(),
+                increment,
+            ],
+            Some(&payer.pubkey()),
+        );
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Increments and snapshot should succeed");
+
+        let state = read_counter(&svm, &counter.pubkey());
+        assert_eq!(state.count, 5);
+        assert_eq!(state.snapshot, 2);
+
+        // ===== Restore back to the snapshot =====
+        let message = Message::new(&[restore], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Restore should succeed");
+
+        assert_counter_eq(&svm, &counter.pubkey(), 2);
+    }
+
+    #[test]
+    fn test_snapshot_and_restore_require_authority() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 0);
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+
+        let send = |svm: &mut LiteSVM,
+                    signer: &Keypair,
+                    instruction: CounterInstruction,
+                    mut accounts: Vec<AccountMeta>| {
+            accounts.insert(0, AccountMeta::new(counter.pubkey(), false));
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &borsh::to_vec(&instruction).expect("Failed to serialize instruction"),
+                accounts,
+            );
+            let message = Message::new(&[instruction], Some(&signer.pubkey()));
+            let transaction = Transaction::new(&[signer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+        let invalid_authority = TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(CounterError::InvalidAuthority as u32),
+        );
+
+        // ===== An intruder can't take a snapshot =====
+        assert_eq!(
+            send(
+                &mut svm,
+                &intruder,
+                CounterInstruction::SnapshotCounter,
+                vec![
+                    AccountMeta::new(intruder.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            ),
+            Err(invalid_authority.clone())
+        );
+
+        // ===== The authority snapshots at 0, then the count moves on =====
+        send(
+            &mut svm,
+            &payer,
+            CounterInstruction::SnapshotCounter,
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        )
+        .expect("Authority snapshot should succeed");
+        send(
+            &mut svm,
+            &payer,
+            CounterInstruction::IncrementCounter,
+            vec![],
+        )
+        .expect("Increment should succeed");
+
+        // ===== An intruder can't roll it back =====
+        assert_eq!(
+            send(
+                &mut svm,
+                &intruder,
+                CounterInstruction::RestoreCounter,
+                vec![AccountMeta::new_readonly(intruder.pubkey(), true)],
+            ),
+            Err(invalid_authority)
+        );
+        assert_counter_eq(&svm, &counter.pubkey(), 1);
+    }
+
+    #[test]
+    fn test_restore_respects_max_value() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 5);
+
+        let instruction = |instruction: CounterInstruction, accounts: Vec<AccountMeta>| {
+            let mut metas = vec![AccountMeta::new(counter.pubkey(), false)];
+            metas.extend(accounts);
+            Instruction::new_with_bytes(
+                program_id,
+                &borsh::to_vec(&instruction).expect("Failed to serialize instruction"),
+                metas,
+            )
+        };
+        let snapshot = instruction(
+            CounterInstruction::SnapshotCounter,
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let reset = instruction(
+            CounterInstruction::ResetCounter,
+            vec![AccountMeta::new_readonly(payer.pubkey(), true)],
+        );
+        let set_max = instruction(
+            CounterInstruction::SetMaxValue { max_value: 3 },
+            vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let restore = instruction(
+            CounterInstruction::RestoreCounter,
+            vec![AccountMeta::new_readonly(payer.pubkey(), true)],
+        );
+
+        // ===== Snapshot at 5, reset, then cap at 3 =====
+        let message = Message::new(&[snapshot, reset, set_max], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Snapshot, reset and cap should succeed");
+
+        // ===== Restoring 5 would exceed the cap =====
+        let message = Message::new(&[restore], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Restore above max_value should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CounterOverflow as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_query_rent_matches_svm_minimum() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let query_data =
+            borsh::to_vec(&CounterInstruction::QueryRent).expect("Failed to serialize instruction");
+        let query_instruction = Instruction::new_with_bytes(program_id, &query_data, vec![]);
+        let message = Message::new(&[query_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let metadata = svm
+            .send_transaction(transaction)
+            .expect("QueryRent should succeed");
+
+        assert_eq!(metadata.return_data.program_id, program_id);
+        let returned = u64::from_le_bytes(
+            metadata
+                .return_data
+                .data
+                .as_slice()
+                .try_into()
+                .expect("Return data should be a u64"),
+        );
+        assert_eq!(
+            returned,
+            svm.minimum_balance_for_rent_exemption(CounterAccount::LEN)
+        );
+    }
+
+    #[test]
+    fn test_idl_lists_every_error_with_stable_code() {
+        let idl: serde_json::Value =
+            serde_json::from_str(include_str!("../idl.json")).expect("idl.json should parse");
+        let errors = idl["program"]["errors"]
+            .as_array()
+            .expect("IDL should have an errors array");
+
+        let expected = [
+            (
+                CounterError::InvalidInstructionData,
+                "invalidInstructionData",
+            ),
+            (CounterError::CounterOverflow, "counterOverflow"),
+            (CounterError::IncorrectProgramId, "incorrectProgramId"),
+            (CounterError::AlreadyInitialized, "alreadyInitialized"),
+            (CounterError::RateLimited, "rateLimited"),
+            (CounterError::CounterUnderflow, "counterUnderflow"),
+            (
+                CounterError::UnexpectedAccountCount,
+                "unexpectedAccountCount",
+            ),
+            (CounterError::InvalidAuthority, "invalidAuthority"),
+            (CounterError::CounterIsPayer, "counterIsPayer"),
+            (CounterError::CounterDisabled, "counterDisabled"),
+            (CounterError::ProgramNotExecutable, "programNotExecutable"),
+            (CounterError::FeatureDisabled, "featureDisabled"),
+            (CounterError::CasMismatch, "casMismatch"),
+            (CounterError::InvalidMerkleProof, "invalidMerkleProof"),
+            (CounterError::NoCommitment, "noCommitment"),
+            (CounterError::RevealMismatch, "revealMismatch"),
+            (CounterError::MissingAccount, "missingAccount"),
+            (CounterError::StaleNonce, "staleNonce"),
+        ];
+        assert_eq!(
+            errors.len(),
+            expected.len(),
+            "Every error should be in the IDL"
+        );
+
+        for (error, name) in expected {
+            let entry = errors
+                .iter()
+                .find(|entry| entry["name"] == name)
+                .unwrap_or_else(|| panic!("{} missing from the IDL", name));
+            let message = error.to_string();
+            assert_eq!(entry["code"], error as u32, "Code of {}", name);
+            assert_eq!(entry["message"], message.as_str(), "Message of {}", name);
+        }
+    }
+
+    #[test]
+    fn test_log_memo_increments_and_logs() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 4);
+
+        let memo_data = borsh::to_vec(&CounterInstruction::LogMemo {
+            message: "counter says hi ✓".to_string(),
+        })
+        .expect("Failed to serialize instruction");
+        let memo_instruction = Instruction::new_with_bytes(
+            program_id,
+            &memo_data,
+            vec![
+                AccountMeta::new(counter.pubkey(), false),
+                AccountMeta::new_readonly(MEMO_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[memo_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let metadata = svm
+            .send_transaction(transaction)
+            .expect("LogMemo should succeed");
+
+        assert_counter_eq(&svm, &counter.pubkey(), 5);
+        assert!(
+            metadata
+                .logs
+                .iter()
+                .any(|log| log.contains("counter says hi ✓")),
+            "Memo should be logged: {:?}",
+            metadata.logs
+        );
+    }
+
+    #[test]
+    fn test_instruction_discriminators_are_pinned() {
+        // Literal opcodes, so reordering variants breaks this loudly. The Anchor
+        // program's `increment_native_counter` hard-codes `[1]` for IncrementCounter.
+        let pinned = [
+            (
+                CounterInstruction::InitializeCounter { initial_value: 42 },
+                0,
+            ),
+            (CounterInstruction::IncrementCounter, 1),
+            (CounterInstruction::IncrementAnchorCounter, 2),
+            (CounterInstruction::IncrementAnchorCounterRaw, 3),
+            (CounterInstruction::IncrementCounterSelfCpi, 4),
+            (CounterInstruction::IncrementCounterCodamaClient, 5),
+        ];
+
+        for (instruction, opcode) in pinned {
+            let data = borsh::to_vec(&instruction).expect("Failed to serialize instruction");
+            assert_eq!(data[0], opcode, "Opcode of {:?}", instruction);
+        }
+
+        // The payload follows the opcode
+        assert_eq!(
+            borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 42 }).unwrap(),
+            [0, 42, 0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap(),
+            [1]
+        );
+    }
+
+    #[test]
+    fn test_decode_anchor_counter_layout() {
+        let authority = Pubkey::new_unique();
+        let mut data = AnchorIx::INITIALIZE_COUNTER_DISCRIMINATOR.to_vec(); // any 8 bytes
+        data.extend_from_slice(&77u64.to_le_bytes());
+        data.extend_from_slice(authority.as_ref());
+
+        assert_eq!(decode_anchor_counter(&data), Some((77, authority)));
+        assert_eq!(decode_anchor_counter(&data[..ANCHOR_COUNTER_LEN - 1]), None);
+    }
+
+    #[test]
+    fn test_counter_account_builder_validates_max_value() {
+        assert_eq!(CounterAccount::new(7).count, 7);
+        assert_eq!(
+            CounterAccount::new(7),
+            CounterAccount {
+                count: 7,
+                ..CounterAccount::default()
+            }
+        );
+
+        // A count above the cap is rejected
+        let err = CounterAccount::builder()
+            .count(11)
+            .max_value(10)
+            .build()
+            .expect_err("Count above max_value should be rejected");
+        assert!(matches!(err, CounterError::CounterOverflow));
+
+        // At the cap, or with no cap at all, is fine
+        let counter = CounterAccount::builder()
+            .count(10)
+            .step(2)
+            .max_value(10)
+            .build()
+            .expect("Count at max_value should be accepted");
+        assert_eq!(
+            (counter.count, counter.step, counter.max_value),
+            (10, 2, 10)
+        );
+        assert!(CounterAccount::builder().count(u64::MAX).build().is_ok());
+    }
+
+    #[test]
+    fn test_compare_and_increment() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 5);
+
+        let mut compare_and_increment = |expected: u64| {
+            let data = borsh::to_vec(&CounterInstruction::CompareAndIncrement { expected })
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![AccountMeta::new(counter.pubkey(), false)],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let result = svm.send_transaction(transaction);
+            svm.expire_blockhash();
+            result.map(|_| ()).map_err(|failed| failed.err)
+        };
+
+        // ===== A stale read is rejected =====
+        assert_eq!(
+            compare_and_increment(4),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CasMismatch as u32)
+            ))
+        );
+
+        // ===== The current value goes through =====
+        compare_and_increment(5).expect("Matching expected value should increment");
+
+        // Now 5 is stale too
+        assert!(compare_and_increment(5).is_err());
+        assert_counter_eq(&svm, &counter.pubkey(), 6);
+    }
+
+    #[test]
+    fn test_close_pda_counter_refunds_payer() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let (counter_pda, _bump) =
+            find_counter_pda(&ProgramPubkey::new_from_array(payer.pubkey().to_bytes()));
+        let counter_pda = Pubkey::new_from_array(counter_pda.to_bytes());
+        let init_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounterPda { initial_value: 3 })
+                .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_pda, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("PDA initialize should succeed");
+
+        let rent = svm.get_balance(&counter_pda).unwrap();
+        let payer_before = svm.get_balance(&payer.pubkey()).unwrap();
+
+        // The payer is the authority and the recipient; the PDA never signs
+        let close_data = borsh::to_vec(&CounterInstruction::CloseCounter)
+            .expect("Failed to serialize instruction");
+        let close_instruction = Instruction::new_with_bytes(
+            program_id,
+            &close_data,
+            vec![
+                AccountMeta::new(counter_pda, false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), false),
+            ],
+        );
+        let message = Message::new(&[close_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(result.is_ok(), "Close should succeed: {:?}", result);
+
+        // LiteSVM charges the default 5000 lamports for the one signature
+        let payer_after = svm.get_balance(&payer.pubkey()).unwrap();
+        assert_eq!(payer_after, payer_before + rent - 5_000);
+        assert!(
+            svm.get_account(&counter_pda)
+                .is_none_or(|account| account.lamports == 0),
+            "Closed counter should be gone"
+        );
+    }
+
+    #[test]
+    fn test_close_counter_requires_authority() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 1);
+        let impostor = Keypair::new();
+        svm.airdrop(&impostor.pubkey(), 1_000_000_000).unwrap();
+
+        let close_data = borsh::to_vec(&CounterInstruction::CloseCounter)
+            .expect("Failed to serialize instruction");
+        let close_instruction = Instruction::new_with_bytes(
+            program_id,
+            &close_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new_readonly(impostor.pubkey(), true),
+                AccountMeta::new(impostor.pubkey(), false),
+            ],
+        );
+        let message = Message::new(&[close_instruction], Some(&impostor.pubkey()));
+        let transaction = Transaction::new(&[&impostor], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Only the authority may close");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 1);
+    }
+
+    #[test]
+    fn test_initialize_idempotent_keeps_first_value() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = Keypair::new();
+
+        for initial_value in [7, 99] {
+            let data =
+                borsh::to_vec(&CounterInstruction::InitializeCounterIdempotent { initial_value })
+                    .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), true),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction =
+                Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+            let result = svm.send_transaction(transaction);
+            assert!(
+                result.is_ok(),
+                "Idempotent initialize with {} should succeed: {:?}",
+                initial_value,
+                result
+            );
+        }
+
+        // The retry is a no-op, so the first value survives
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 7);
+    }
+
+    #[test]
+    fn test_increment_warns_when_not_rent_exempt() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 4);
+
+        // Drain the counter below the rent-exempt minimum
+        let mut account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        account.lamports = svm.minimum_balance_for_rent_exemption(CounterAccount::LEN) / 2;
+        svm.set_account(counter_keypair.pubkey(), account).unwrap();
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+        let message = Message::new(&[increment_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let meta = svm
+            .send_transaction(transaction)
+            .expect("A warning should not fail the increment");
+
+        assert!(meta
+            .logs
+            .iter()
+            .any(|log| log.contains("Warning: Counter is not rent-exempt")));
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 5);
+    }
+
+    #[test]
+    fn test_initialize_and_increment_in_one_transaction() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = Keypair::new();
+        let initial_value = 20;
+
+        let init_instruction = initialize_ix(
+            program_id,
+            &counter_keypair.pubkey(),
+            &payer.pubkey(),
+            initial_value,
+        );
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let increment_instruction = Instruction::new_with_bytes(
+            program_id,
+            &increment_data,
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+        );
+
+        // Both instructions land atomically: the second sees the account the first created
+        let message = Message::new(
+            &[init_instruction, increment_instruction],
+            Some(&payer.pubkey()),
+        );
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Init + increment transaction should succeed: {:?}",
+            result
+        );
+
+        let account = svm
+            .get_account(&counter_keypair.pubkey())
+            .expect("Counter should have been created");
+        assert_eq!(account.owner, program_id);
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), initial_value + 1);
+    }
+
+    #[test]
+    fn test_self_cpi_logs_cpi_trace() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        let self_cpi_data = borsh::to_vec(&CounterInstruction::IncrementCounterSelfCpi)
+            .expect("Failed to serialize");
+        let self_cpi_tx = Transaction::new_signed_with_payer(
+            &[Instruction::new_with_bytes(
+                program_id,
+                &self_cpi_data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), false),
+                    AccountMeta::new_readonly(program_id, false),
+                ],
+            )],
+            Some(&payer.pubkey()),
+            &[&payer],
+            svm.latest_blockhash(),
+        );
+        let meta = svm.send_transaction(self_cpi_tx).unwrap();
+
+        // The one-byte IncrementCounter payload, traced before the nested invoke
+        let trace = format!("CPI -> {} (1 bytes)", program_id);
+        assert!(
+            meta.logs.iter().any(|log| log.contains(&trace)),
+            "Missing {:?} in {:#?}",
+            trace,
+            meta.logs
+        );
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 1);
+    }
+
+    #[test]
+    fn test_delegate_can_increment_until_cleared() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+        let counter = counter_keypair.pubkey();
+        let delegate = Keypair::new();
+        let stranger = Keypair::new();
+        for signer in [&delegate, &stranger] {
+            svm.airdrop(&signer.pubkey(), 1_000_000_000).unwrap();
+        }
+
+        let set_delegate = |svm: &mut LiteSVM, delegate: Option<Pubkey>| {
+            let delegate = delegate.map(|key| ProgramPubkey::new_from_array(key.to_bytes()));
+            let data = borsh::to_vec(&CounterInstruction::SetDelegate { delegate })
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect("Authority should be able to set the delegate");
+        };
+        let increment_as = |svm: &mut LiteSVM, signer: &Keypair| {
+            let data = borsh::to_vec(&CounterInstruction::IncrementAsDelegate)
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter, false),
+                    AccountMeta::new_readonly(signer.pubkey(), true),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&signer.pubkey()));
+            let transaction = Transaction::new(&[signer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+        let invalid_authority = Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(CounterError::InvalidAuthority as u32),
+        ));
+
+        // ===== The delegate can increment, a random signer cannot =====
+        set_delegate(&mut svm, Some(delegate.pubkey()));
+        assert_eq!(increment_as(&mut svm, &delegate), Ok(()));
+        assert_eq!(increment_as(&mut svm, &stranger), invalid_authority);
+        assert_counter_eq(&svm, &counter, 1);
+
+        // ===== Clearing the delegate revokes it; the authority keeps working =====
+        set_delegate(&mut svm, None);
+        svm.expire_blockhash();
+        assert_eq!(increment_as(&mut svm, &delegate), invalid_authority);
+        assert_eq!(increment_as(&mut svm, &payer), Ok(()));
+        assert_counter_eq(&svm, &counter, 2);
+        assert_eq!(read_counter(&svm, &counter).delegate, None);
+    }
+
+    #[test]
+    fn test_cpi_cannot_escalate_authority_signer() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+        let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id, 3);
+
+        // The stored authority is passed in, but never signs the outer transaction
+        let send_unsigned = |svm: &mut LiteSVM, instruction: CounterInstruction| {
+            let data = borsh::to_vec(&instruction).expect("Failed to serialize instruction");
+            let cpi_instruction = Instruction::new_with_bytes(
+                native_program_id,
+                &data,
+                vec![
+                    AccountMeta::new(anchor_counter.pubkey(), false),
+                    AccountMeta::new_readonly(payer.pubkey(), false),
+                    AccountMeta::new_readonly(anchor_program_id, false),
+                ],
+            );
+            // A separate fee payer keeps the authority off the signature list
+            let fee_payer = Keypair::new();
+            svm.airdrop(&fee_payer.pubkey(), 1_000_000_000).unwrap();
+            let message = Message::new(&[cpi_instruction], Some(&fee_payer.pubkey()));
+            let transaction = Transaction::new(&[&fee_payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect_err("An unsigned authority must not pass through a CPI")
+                .err
+        };
+
+        // ===== Typed client: the flag is forwarded as-is and Anchor rejects it =====
+        // Anchor's `Signer` check reports AccountNotSigner (3010)
+        assert_eq!(
+            send_unsigned(&mut svm, CounterInstruction::IncrementAnchorCounter),
+            TransactionError::InstructionError(0, InstructionError::Custom(3010))
+        );
+
+        // ===== Raw CPI: claiming the signer flag is caught by the runtime =====
+        // The raw handler marks the authority as a signer in its AccountMeta, but a
+        // program can only extend signatures the outer transaction carried (or its
+        // own PDAs), so the invoke fails before Anchor runs
+        assert_eq!(
+            send_unsigned(&mut svm, CounterInstruction::IncrementAnchorCounterRaw),
+            TransactionError::InstructionError(0, InstructionError::PrivilegeEscalation)
+        );
+
+        assert_eq!(read_anchor_count(&svm, &anchor_counter.pubkey()), 3);
+    }
+
+    #[test]
+    fn test_withdraw_surplus_keeps_counter_rent_exempt() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+        let counter = counter_keypair.pubkey();
+        let minimum_balance = svm.minimum_balance_for_rent_exemption(CounterAccount::LEN);
+
+        // Someone tips the counter well beyond what it needs
+        let surplus = 500_000_000;
+        let mut account = svm.get_account(&counter).unwrap();
+        account.lamports += surplus;
+        svm.set_account(counter, account).unwrap();
+
+        let destination = Pubkey::new_unique();
+        let withdraw_data = borsh::to_vec(&CounterInstruction::WithdrawSurplus)
+            .expect("Failed to serialize instruction");
+        let withdraw_instruction = Instruction::new_with_bytes(
+            program_id,
+            &withdraw_data,
+            vec![
+                AccountMeta::new(counter, false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new(destination, false),
+            ],
+        );
+        let message = Message::new(&[withdraw_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(result.is_ok(), "Withdraw should succeed: {:?}", result);
+
+        // Exactly the surplus moved; the counter is left at its rent-exempt minimum
+        assert_eq!(svm.get_balance(&destination), Some(surplus));
+        assert_eq!(svm.get_balance(&counter), Some(minimum_balance));
+        assert_counter_eq(&svm, &counter, 0);
+    }
+
+    #[test]
+    fn test_type_reexports_resolve() {
+        // Both Pubkeys resolve, and convert losslessly through their bytes
+        let key = Pubkey::new_unique();
+        let program_key = ProgramPubkey::new_from_array(key.to_bytes());
+        assert_eq!(Pubkey::new_from_array(program_key.to_bytes()), key);
+
+        // The instruction types are the ones LiteSVM transactions are built from
+        let meta = AccountMeta::new_readonly(key, true);
+        let instruction = Instruction::new_with_bytes(key, &[1], vec![meta.clone()]);
+        let message = Message::new(&[instruction], Some(&key));
+        assert_eq!(message.account_keys, vec![key]);
+        assert!(meta.is_signer && !meta.is_writable);
+    }
+
+    #[test]
+    fn test_merkle_proof_verifies_sorted_pairs() {
+        let leaves: Vec<[u8; 32]> = (1..=4u8)
+            .map(|i| merkle::hash_leaf(&ProgramPubkey::new_from_array([i; 32])))
+            .collect();
+        let left = merkle::hash_pair(&leaves[0], &leaves[1]);
+        let right = merkle::hash_pair(&leaves[2], &leaves[3]);
+        let root = merkle::hash_pair(&left, &right);
+
+        // Sibling order doesn't matter, only the path
+        assert_eq!(
+            merkle::hash_pair(&left, &right),
+            merkle::hash_pair(&right, &left)
+        );
+        assert!(merkle::verify_proof(&root, &leaves[2], &[leaves[3], left]));
+        assert!(merkle::verify_proof(&root, &leaves[1], &[leaves[0], right]));
+
+        // A leaf from outside the tree, or a proof for a different leaf, fails
+        let outsider = merkle::hash_leaf(&ProgramPubkey::new_from_array([9; 32]));
+        assert!(!merkle::verify_proof(&root, &outsider, &[leaves[3], left]));
+        assert!(!merkle::verify_proof(&root, &leaves[0], &[leaves[3], left]));
+
+        // Leaves and internal nodes are hashed in separate domains
+        assert_ne!(
+            merkle::hash_pair(&leaves[0], &leaves[1]),
+            solana_keccak_hasher::hashv(&[&leaves[0], &leaves[1]]).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_increment_if_whitelisted() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // A four-member tree: root = H(H(l0, l1), H(l2, l3)), each leaf hashed from an address
+        let members: Vec<Keypair> = (0..4).map(|_| Keypair::new()).collect();
+        let leaves: Vec<[u8; 32]> = members
+            .iter()
+            .map(|member| {
+                merkle::hash_leaf(&ProgramPubkey::new_from_array(member.pubkey().to_bytes()))
+            })
+            .collect();
+        let left = merkle::hash_pair(&leaves[0], &leaves[1]);
+        let right = merkle::hash_pair(&leaves[2], &leaves[3]);
+        let merkle_root = merkle::hash_pair(&left, &right);
+
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeWhitelistCounter {
+            initial_value: 0,
+            merkle_root,
+        })
+        .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Whitelist initialize should succeed");
+
+        let mut increment = |proof: Vec<[u8; 32]>, member: &Keypair| {
+            let data = borsh::to_vec(&CounterInstruction::IncrementIfWhitelisted { proof })
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), false),
+                    AccountMeta::new_readonly(member.pubkey(), true),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer, member], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+        let invalid_proof = Err(TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(CounterError::InvalidMerkleProof as u32),
+        ));
+
+        // ===== Member 2 signs with a valid proof and increments =====
+        assert_eq!(increment(vec![leaves[3], left], &members[2]), Ok(()));
+
+        // ===== Tampering with a sibling breaks the path to the root =====
+        let mut tampered = left;
+        tampered[0] ^= 1;
+        assert_eq!(
+            increment(vec![leaves[3], tampered], &members[2]),
+            invalid_proof
+        );
+
+        // ===== Someone else can't reuse member 2's proof =====
+        assert_eq!(
+            increment(vec![leaves[3], left], &Keypair::new()),
+            invalid_proof
+        );
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        let counter = WhitelistCounterAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(counter.count, 1);
+        assert_eq!(counter.merkle_root, merkle_root);
+    }
+
+    #[test]
+    fn test_commit_and_reveal_increment() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+        let counter = counter_keypair.pubkey();
+        let (commitment, _bump) =
+            find_commitment_pda(&ProgramPubkey::new_from_array(counter.to_bytes()));
+        let commitment = Pubkey::new_from_array(commitment.to_bytes());
+
+        let send = |svm: &mut LiteSVM, instruction: CounterInstruction| {
+            let data = borsh::to_vec(&instruction).expect("Failed to serialize instruction");
+            let mut accounts = vec![
+                AccountMeta::new(counter, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new(commitment, false),
+            ];
+            if matches!(instruction, CounterInstruction::CommitIncrement { .. }) {
+                accounts[0].is_writable = false;
+                accounts.push(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false));
+            }
+            let message = Message::new(
+                &[Instruction::new_with_bytes(program_id, &data, accounts)],
+                Some(&payer.pubkey()),
+            );
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+        let custom = |error: CounterError| {
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(error as u32),
+            ))
+        };
+
+        // ===== Revealing before anything was committed fails =====
+        assert_eq!(
+            send(&mut svm, CounterInstruction::RevealIncrement { nonce: 42 }),
+            custom(CounterError::NoCommitment)
+        );
+
+        // ===== Commit, then a wrong nonce is rejected =====
+        let hash = CommitmentAccount::hash_nonce(42);
+        assert_eq!(
+            send(&mut svm, CounterInstruction::CommitIncrement { hash }),
+            Ok(())
+        );
+        assert_eq!(
+            send(&mut svm, CounterInstruction::RevealIncrement { nonce: 41 }),
+            custom(CounterError::RevealMismatch)
+        );
+        assert_counter_eq(&svm, &counter, 0);
+
+        // ===== The right nonce increments and closes the commitment =====
+        assert_eq!(
+            send(&mut svm, CounterInstruction::RevealIncrement { nonce: 42 }),
+            Ok(())
+        );
+        assert_counter_eq(&svm, &counter, 1);
+        assert!(svm
+            .get_account(&commitment)
+            .is_none_or(|account| account.lamports == 0));
+
+        // The commitment is spent, so the same nonce can't be replayed
+        svm.expire_blockhash();
+        assert_eq!(
+            send(&mut svm, CounterInstruction::RevealIncrement { nonce: 42 }),
+            custom(CounterError::NoCommitment)
+        );
+    }
+
+    #[test]
+    fn test_counter_errors_map_to_stable_custom_codes() {
+        use solana_program::program_error::ProgramError;
+
+        // The documented code of every variant. The match is exhaustive, so a new
+        // variant doesn't compile until its code is pinned here too.
+        let documented_code = |error: &CounterError| -> u32 {
+            match error {
+                CounterError::InvalidInstructionData => 0,
+                CounterError::CounterOverflow => 1,
+                CounterError::IncorrectProgramId => 2,
+                CounterError::AlreadyInitialized => 3,
+                CounterError::RateLimited => 4,
+                CounterError::CounterUnderflow => 5,
+                CounterError::UnexpectedAccountCount => 6,
+                CounterError::InvalidAuthority => 7,
+                CounterError::CounterIsPayer => 8,
+                CounterError::CounterDisabled => 9,
+                CounterError::ProgramNotExecutable => 10,
+                CounterError::FeatureDisabled => 11,
+                CounterError::CasMismatch => 12,
+                CounterError::InvalidMerkleProof => 13,
+                CounterError::NoCommitment => 14,
+                CounterError::RevealMismatch => 15,
+                CounterError::MissingAccount => 16,
+                CounterError::StaleNonce => 17,
+            }
+        };
+        let all = [
+            CounterError::InvalidInstructionData,
+            CounterError::CounterOverflow,
+            CounterError::IncorrectProgramId,
+            CounterError::AlreadyInitialized,
+            CounterError::RateLimited,
+            CounterError::CounterUnderflow,
+            CounterError::UnexpectedAccountCount,
+            CounterError::InvalidAuthority,
+            CounterError::CounterIsPayer,
+            CounterError::CounterDisabled,
+            CounterError::ProgramNotExecutable,
+            CounterError::FeatureDisabled,
+            CounterError::CasMismatch,
+            CounterError::InvalidMerkleProof,
+            CounterError::NoCommitment,
+            CounterError::RevealMismatch,
+            CounterError::MissingAccount,
+            CounterError::StaleNonce,
+        ];
+
+        for error in all {
+            let code = documented_code(&error);
+            let message = error.to_string();
+            assert_eq!(
+                ProgramError::from(error),
+                ProgramError::Custom(code),
+                "{} should map to Custom({})",
+                message,
+                code
+            );
+
+            // What a client does with a failed transaction: code back to message
+            assert_eq!(error.code(), code);
+            let decoded = CounterError::from_code(code);
+            assert_eq!(decoded, Some(error));
+            assert_eq!(decoded.map(|error| error.to_string()), Some(message));
+        }
+
+        // The codes are contiguous, and the first unused one decodes to nothing
+        assert_eq!(CounterError::from_code(all.len() as u32), None);
+        assert_eq!(CounterError::from_code(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_anchor_increment_many_remaining_accounts() {
+        let (mut svm, payer, _native_program_id, anchor_program_id) = setup_svm();
+        let counters: Vec<Keypair> = (1..=3)
+            .map(|value| init_anchor_counter(&mut svm, &payer, anchor_program_id, value))
+            .collect();
+
+        // The authority is the only named account; the counters ride along after it
+        let mut accounts = vec![AccountMeta::new_readonly(payer.pubkey(), true)];
+        accounts.extend(
+            counters
+                .iter()
+                .map(|counter| AccountMeta::new(counter.pubkey(), false)),
+        );
+        let instruction = Instruction::new_with_bytes(
+            anchor_program_id,
+            &AnchorIx::IncrementMany.to_data(),
+            accounts,
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "increment_many should succeed: {:?}",
+            result
+        );
+
+        for (counter, initial_value) in counters.iter().zip(1..) {
+            assert_eq!(
+                read_anchor_count(&svm, &counter.pubkey()),
+                initial_value + 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_record_epoch() {
+        use solana_sdk::clock::Clock;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+        assert_eq!(read_counter(&svm, &counter_keypair.pubkey()).last_epoch, 0);
+
+        // Move the SVM into a later epoch
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.epoch += 3;
+        let target_epoch = clock.epoch;
+        svm.set_sysvar(&clock);
+
+        let record = |svm: &mut LiteSVM, authority: &Keypair| {
+            let record_data = borsh::to_vec(&CounterInstruction::RecordEpoch)
+                .expect("Failed to serialize instruction");
+            let record_instruction = Instruction::new_with_bytes(
+                program_id,
+                &record_data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), false),
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[record_instruction], Some(&authority.pubkey()));
+            let transaction = Transaction::new(&[authority], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+
+        // ===== Someone else can't stamp the counter =====
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+        assert_eq!(
+            record(&mut svm, &intruder),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            ))
+        );
+        assert_eq!(read_counter(&svm, &counter_keypair.pubkey()).last_epoch, 0);
+
+        // ===== The authority can =====
+        let result = record(&mut svm, &payer);
+        assert!(result.is_ok(), "RecordEpoch should succeed: {:?}", result);
+
+        assert_eq!(
+            read_counter(&svm, &counter_keypair.pubkey()).last_epoch,
+            target_epoch
+        );
+    }
+
+    #[test]
+    fn test_initialize_counter_with_reserved_space() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        let init_with_size = |svm: &mut LiteSVM, reserved: u16| {
+            let counter_keypair = Keypair::new();
+            let data = borsh::to_vec(&CounterInstruction::InitializeCounterWithSize {
+                initial_value: 77,
+                reserved,
+            })
+            .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter_keypair.pubkey(), true),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction =
+                Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| counter_keypair.pubkey())
+                .map_err(|failed| failed.err)
+        };
+
+        // ===== 200 reserved bytes after the count =====
+        let counter = init_with_size(&mut svm, 200).expect("Sized initialize should succeed");
+        let account = svm.get_account(&counter).unwrap();
+        assert_eq!(account.data().len(), 8 + 200);
+        assert_eq!(
+            u64::from_le_bytes(account.data()[..8].try_into().unwrap()),
+            77
+        );
+        assert_counter_eq(&svm, &counter, 77);
+
+        // ===== Too little to hold the current layout: rounded up, with an authority =====
+        let counter = init_with_size(&mut svm, 0).expect("Sized initialize should succeed");
+        let account = svm.get_account(&counter).unwrap();
+        assert_eq!(account.data().len(), CounterAccount::LEN);
+        assert_eq!(
+            read_counter(&svm, &counter).authority.to_bytes(),
+            payer.pubkey().to_bytes()
+        );
+
+        // ===== Anything over 10 KiB is refused =====
+        assert_eq!(
+            init_with_size(&mut svm, u16::MAX),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::InvalidArgument
+            ))
+        );
+    }
+
+    #[test]
+    fn test_append_log_grows_counter_account() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 5);
+        let counter = counter_keypair.pubkey();
+
+        let append = |svm: &mut LiteSVM, authority: &Keypair, entry: u64| {
+            let data = borsh::to_vec(&CounterInstruction::AppendLog { entry })
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter, false),
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&authority.pubkey()));
+            let transaction = Transaction::new(&[authority], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+
+        let entries = [11u64, 22, 33];
+        for entry in entries {
+            let result = append(&mut svm, &payer, entry);
+            assert!(result.is_ok(), "AppendLog should succeed: {:?}", result);
+        }
+
+        // ===== Someone else can't write to (or grow) the counter, even paying for it =====
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+        assert_eq!(
+            append(&mut svm, &intruder, 44),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            ))
+        );
+
+        let account = svm.get_account(&counter).unwrap();
+        assert_eq!(
+            account.data().len(),
+            CounterAccount::LEN
+                + CounterAccount::LOG_COUNT_LEN
+                + entries.len() * CounterAccount::LOG_ENTRY_LEN
+        );
+        assert_eq!(CounterAccount::log_entries(account.data()), entries);
+        assert!(account.lamports() >= svm.minimum_balance_for_rent_exemption(account.data().len()));
+
+        // The counter fields in front of the log are untouched
+        assert_counter_eq(&svm, &counter, 5);
+    }
+
+    #[test]
+    fn test_append_log_uses_reserved_space() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = Keypair::new();
+        let counter = counter_keypair.pubkey();
+
+        // ===== Pre-size the counter with zeroed room well past the counter fields =====
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeCounterWithSize {
+            initial_value: 5,
+            reserved: 200,
+        })
+        .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter, true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Sized initialize should succeed");
+
+        // The reserved zeroes are not log entries
+        let account = svm.get_account(&counter).unwrap();
+        assert!(CounterAccount::log_entries(account.data()).is_empty());
+
+        // ===== Appending fills the reserved space instead of growing =====
+        let entries = [11u64, 22];
+        for entry in entries {
+            let data = borsh::to_vec(&CounterInstruction::AppendLog { entry })
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter, false),
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect("AppendLog should succeed");
+        }
+
+        let account = svm.get_account(&counter).unwrap();
+        assert_eq!(account.data().len(), 8 + 200);
+        assert_eq!(CounterAccount::log_len(account.data()), entries.len());
+        assert_eq!(CounterAccount::log_entries(account.data()), entries);
+        assert_counter_eq(&svm, &counter, 5);
+    }
+
+    #[test]
+    fn test_program_id_helpers_match_declare_id() {
+        use std::str::FromStr;
+
+        assert_eq!(program_id_str(), COUNTER_PROGRAM_ID.to_string());
+        assert_eq!(ProgramPubkey::from_str(program_id_str()), Ok(crate::id()));
+        assert_eq!(program_id(), COUNTER_PROGRAM_ID);
+    }
+
+    #[test]
+    fn test_registry_counters_stay_independent() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let mut registry = CounterRegistry::new(program_id);
+
+        let counters: Vec<_> = (0..5)
+            .map(|i| registry.init(&mut svm, &payer, i * 100))
+            .collect();
+        registry.verify_all(&svm);
+
+        // Counter i is incremented i times, interleaved with the others
+        for round in 0..counters.len() {
+            for (i, counter) in counters.iter().enumerate() {
+                if round < i {
+                    registry.increment(&mut svm, &payer, counter);
+                }
+            }
+            registry.verify_all(&svm);
+        }
+
+        assert_counter_eq(&svm, &counters[0], 0);
+        assert_counter_eq(&svm, &counters[4], 404);
+    }
+
+    #[test]
+    fn test_init_and_increment_self_cpi() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = Keypair::new();
+        let initial_value = 30;
+
+        let data = borsh::to_vec(&CounterInstruction::InitAndIncrementSelfCpi { initial_value })
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(program_id, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        let result = svm.send_transaction(transaction);
+        assert!(
+            result.is_ok(),
+            "Init + self-CPI increment should succeed: {:?}",
+            result
+        );
+
+        // One outer instruction, with the increment nested as a CPI into ourselves
+        let logs = result.unwrap().logs;
+        assert!(logs
+            .iter()
+            .any(|log| log == &format!("Program {} invoke [2]", program_id)));
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), initial_value + 1);
+    }
+
+    #[test]
+    fn test_missing_account_is_named() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // IncrementCounter without its counter account
+        let data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(program_id, &data, vec![]);
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Missing counter should be rejected");
+
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::MissingAccount as u32)
+            )
+        );
+        assert!(err
+            .meta
+            .logs
+            .iter()
+            .any(|log| log.contains("Error: Missing counter account")));
+    }
+
+    #[test]
+    fn test_anchor_ix_data_matches_idl_discriminators() {
+        // Discriminators as listed in anchor-counter/target/idl/anchor_counter.json
+        assert_eq!(
+            AnchorIx::IncrementCounter.to_data(),
+            [16, 125, 2, 171, 73, 24, 207, 229]
+        );
+        assert_eq!(
+            AnchorIx::IncrementMany.to_data(),
+            [138, 226, 160, 220, 47, 40, 184, 125]
+        );
+        assert_eq!(
+            AnchorIx::IncrementNativeCounter.to_data(),
+            [103, 124, 36, 136, 151, 180, 192, 39]
+        );
+
+        // Arguments follow the discriminator as borsh
+        let data = AnchorIx::InitializeCounter { initial_value: 5 }.to_data();
+        assert_eq!(data[..8], [67, 89, 100, 87, 231, 172, 35, 124]);
+        assert_eq!(data[8..], 5u64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_anchor_cpi_precheck_rejects_non_counter_accounts() {
+        let (mut svm, payer, program_id, anchor_program_id) = setup_svm();
+
+        let increment_raw = |svm: &mut LiteSVM, target: Pubkey| {
+            let data = borsh::to_vec(&CounterInstruction::IncrementAnchorCounterRaw)
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(target, false),
+                    AccountMeta::new_readonly(payer.pubkey(), true),
+                    AccountMeta::new_readonly(anchor_program_id, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect_err("Pre-check should reject the account")
+        };
+        let cpi_started = |logs: &[String]| {
+            logs.iter()
+                .any(|log| log == &format!("Program {} invoke [2]", anchor_program_id))
+        };
+
+        // ===== Owned by the Anchor program, but not a Counter =====
+        let mut data = vec![0u8; ANCHOR_COUNTER_LEN];
+        data[..8].copy_from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let impostor = Pubkey::new_unique();
+        svm.set_account(
+            impostor,
+            solana_sdk::account::Account {
+                lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: anchor_program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let err = increment_raw(&mut svm, impostor);
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::InvalidAccountData)
+        );
+        assert!(err
+            .meta
+            .logs
+            .iter()
+            .any(|log| log.contains("is not an Anchor Counter account")));
+        assert!(!cpi_started(&err.meta.logs));
+
+        // ===== A native counter is not the Anchor program's to increment =====
+        let native_counter = init_counter(&mut svm, &payer, program_id, 0);
+        let err = increment_raw(&mut svm, native_counter.pubkey());
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(0, InstructionError::IllegalOwner)
+        );
+        assert!(!cpi_started(&err.meta.logs));
+        assert_counter_eq(&svm, &native_counter.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_stats_account_totals_increments_across_counters() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let (stats_pda, _bump) = Pubkey::find_program_address(&[STATS_SEED], &program_id);
+
+        let data = borsh::to_vec(&CounterInstruction::InitializeStats).unwrap();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new(stats_pda, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(solana_program::system_program::ID, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Stats initialization failed");
+
+        // ===== One increment on each of two counters, both reporting to the stats PDA =====
+        let first = init_counter(&mut svm, &payer, program_id, 0);
+        let second = init_counter(&mut svm, &payer, program_id, 10);
+        let data = borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap();
+        for counter in [first.pubkey(), second.pubkey()] {
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                // The omitted payer and System Program are passed as the program ID
+                vec![
+                    AccountMeta::new(counter, false),
+                    AccountMeta::new_readonly(program_id, false),
+                    AccountMeta::new_readonly(program_id, false),
+                    AccountMeta::new(stats_pda, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect("Increment with stats failed");
+        }
+
+        assert_counter_eq(&svm, &first.pubkey(), 1);
+        assert_counter_eq(&svm, &second.pubkey(), 11);
+
+        let stats_account = svm.get_account(&stats_pda).expect("Stats account missing");
+        let stats = StatsAccount::try_from_slice(stats_account.data()).unwrap();
+        assert_eq!(stats.total_increments, 2);
+    }
+
+    #[test]
+    fn test_malformed_instruction_data_is_logged() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        let send_raw = |svm: &mut LiteSVM, data: &[u8]| {
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                data,
+                vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .expect_err("Malformed instruction data should be rejected")
+        };
+
+        // ===== A variant index no version of the program has =====
+        let err = send_raw(&mut svm, &[200]);
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidInstructionData as u32)
+            )
+        );
+        assert!(err
+            .meta
+            .logs
+            .iter()
+            .any(|log| log.contains("Unexpected variant index 200")));
+
+        // ===== A known opcode whose payload is cut short: borsh's reason is kept =====
+        let err = send_raw(&mut svm, &[7, 1, 0]);
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidInstructionData as u32)
+            )
+        );
+        assert!(err
+            .meta
+            .logs
+            .iter()
+            .any(|log| log.contains("Failed to deserialize opcode 7: Unexpected length of input")));
+
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 0);
+    }
+
+    #[test]
+    fn test_initialize_counters_batch() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counters: Vec<Keypair> = (0..3).map(|_| Keypair::new()).collect();
+
+        let data = borsh::to_vec(&CounterInstruction::InitializeCountersBatch {
+            count: 3,
+            initial_value: 42,
+        })
+        .unwrap();
+        let mut accounts = vec![
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(solana_program::system_program::ID, false),
+        ];
+        accounts.extend(
+            counters
+                .iter()
+                .map(|counter| AccountMeta::new(counter.pubkey(), true)),
+        );
+        let instruction = Instruction::new_with_bytes(program_id, &data, accounts);
+
+        let mut signers = vec![&payer];
+        signers.extend(counters.iter());
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&signers, message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Batch initialization failed");
+
+        for counter in &counters {
+            let account = read_counter(&svm, &counter.pubkey());
+            assert_eq!(account.count, 42);
+            assert_eq!(account.authority.to_bytes(), payer.pubkey().to_bytes());
+        }
+    }
+
+    #[test]
+    fn test_counter_account_u64_conversions_round_trip() {
+        let counter = CounterAccount::from(42);
+        assert_eq!(counter.count, 42);
+        assert_eq!(
+            counter,
+            CounterAccount {
+                count: 42,
+                ..CounterAccount::default()
+            }
+        );
+        assert_eq!(u64::from(counter), 42);
+
+        // Only the count survives the trip through u64
+        let counter = CounterAccount::builder().count(7).step(3).build().unwrap();
+        let count: u64 = counter.into();
+        assert_eq!(CounterAccount::from(count), CounterAccount::from(7));
+    }
+
+    #[test]
+    fn test_increment_with_nonce_rejects_replays() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 0);
+
+        let increment_with_nonce = |svm: &mut LiteSVM, nonce: u64| {
+            let data =
+                borsh::to_vec(&CounterInstruction::IncrementCounterWithNonce { nonce }).unwrap();
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let result = svm
+                .send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err);
+            svm.expire_blockhash();
+            result
+        };
+
+        increment_with_nonce(&mut svm, 5).expect("First use of a nonce should succeed");
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 1);
+
+        // ===== Same nonce again: a replay =====
+        let err = increment_with_nonce(&mut svm, 5).expect_err("Replayed nonce should fail");
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::StaleNonce as u32)
+            )
+        );
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 1);
+
+        // ===== A higher nonce goes through =====
+        increment_with_nonce(&mut svm, 6).expect("Higher nonce should succeed");
+        let counter = read_counter(&svm, &counter_keypair.pubkey());
+        assert_eq!(counter.count, 2);
+        assert_eq!(counter.nonce, 6);
+    }
+
+    #[test]
+    fn test_copy_counter() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let source = init_counter(&mut svm, &payer, program_id, 42);
+        let destination = init_counter(&mut svm, &payer, program_id, 0);
+
+        let data = borsh::to_vec(&CounterInstruction::CopyCounter).unwrap();
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &data,
+            vec![
+                AccountMeta::new_readonly(source.pubkey(), false),
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction).expect("Copy failed");
+
+        assert_counter_eq(&svm, &destination.pubkey(), 42);
+        assert_counter_eq(&svm, &source.pubkey(), 42);
+    }
+
+    #[test]
+    fn test_copy_counter_requires_destination_authority_and_cap() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let zero = init_counter(&mut svm, &payer, program_id, 0);
+        let high = init_counter(&mut svm, &payer, program_id, 42);
+        let destination = init_counter(&mut svm, &payer, program_id, 5);
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+
+        let copy = |svm: &mut LiteSVM, source: &Pubkey, signer: &Keypair| {
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &borsh::to_vec(&CounterInstruction::CopyCounter).unwrap(),
+                vec![
+                    AccountMeta::new_readonly(*source, false),
+                    AccountMeta::new(destination.pubkey(), false),
+                    AccountMeta::new_readonly(signer.pubkey(), true),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&signer.pubkey()));
+            let transaction = Transaction::new(&[signer], message, svm.latest_blockhash());
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+
+        // ===== Copying from a zero counter would reset someone else's =====
+        assert_eq!(
+            copy(&mut svm, &zero.pubkey(), &intruder),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            ))
+        );
+        assert_counter_eq(&svm, &destination.pubkey(), 5);
+
+        // ===== Even the authority can't copy past the destination's cap =====
+        let set_max = Instruction::new_with_bytes(
+            program_id,
+            &borsh::to_vec(&CounterInstruction::SetMaxValue { max_value: 10 }).unwrap(),
+            vec![
+                AccountMeta::new(destination.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[set_max], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("SetMaxValue failed");
+
+        assert_eq!(
+            copy(&mut svm, &high.pubkey(), &payer),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CounterOverflow as u32)
+            ))
+        );
+        assert_counter_eq(&svm, &destination.pubkey(), 5);
+    }
+
+    #[test]
+    fn test_try_from_slice_compat_reads_legacy_and_current_layouts() {
+        // ===== A counter from before `step` existed: just the count =====
+        let legacy = 42u64.to_le_bytes();
+        assert_eq!(legacy.len(), CounterAccount::LEGACY_LEN);
+        assert_eq!(
+            CounterAccount::try_from_slice_compat(&legacy).unwrap(),
+            CounterAccount::from(42)
+        );
+
+        // ===== The full current layout: every field =====
+        let counter = CounterAccount::builder()
+            .count(7)
+            .step(3)
+            .max_value(100)
+            .authority(ProgramPubkey::new_from_array([4; 32]))
+            .overflow_behavior(OverflowBehavior::Wrap)
+            .build()
+            .unwrap();
+        let full = borsh::to_vec(&counter).unwrap();
+        assert_eq!(full.len(), CounterAccount::LEN);
+        assert_eq!(
+            CounterAccount::try_from_slice_compat(&full).unwrap(),
+            counter
+        );
+
+        // Too short to even hold a count
+        assert!(CounterAccount::try_from_slice_compat(&legacy[..4]).is_err());
+    }
+
+    #[test]
+    fn test_cpi_native_to_anchor_and_back() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+        let native_counter = init_counter(&mut svm, &payer, native_program_id, 10);
+        let anchor_counter = init_anchor_counter(&mut svm, &payer, anchor_program_id, 20);
+
+        // Native -> Anchor: the native program CPIs into Anchor's increment_counter
+        let native_to_anchor = Instruction::new_with_bytes(
+            native_program_id,
+            &borsh::to_vec(&CounterInstruction::IncrementAnchorCounterRaw).unwrap(),
+            vec![
+                AccountMeta::new(anchor_counter.pubkey(), false),
+                AccountMeta::new_readonly(payer.pubkey(), true),
+                AccountMeta::new_readonly(anchor_program_id, false),
+            ],
+        );
+        // Anchor -> native: increment_native_counter CPIs into IncrementCounter
+        //
+        // The runtime only allows a program to reappear on the CPI stack directly
+        // above itself, so native -> Anchor -> native within one instruction is
+        // rejected as reentrancy; the way back is its own top-level instruction.
+        let anchor_to_native = Instruction::new_with_bytes(
+            anchor_program_id,
+            &AnchorIx::IncrementNativeCounter.to_data(),
+            vec![
+                AccountMeta::new(native_counter.pubkey(), false),
+                AccountMeta::new_readonly(native_program_id, false),
+            ],
+        );
+
+        let message = Message::new(&[native_to_anchor, anchor_to_native], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let logs = run_and_get_logs(&mut svm, transaction);
+
+        // Each direction went one CPI deep
+        for callee in [anchor_program_id, native_program_id] {
+            assert!(
+                logs.contains(&format!("Program {} invoke [2]", callee)),
+                "Expected a CPI into {}: {:?}",
+                callee,
+                logs
+            );
+        }
+        assert!(!logs.iter().any(|log| log.contains("invoke [3]")));
+
+        // Both counters advanced
+        assert_eq!(read_anchor_count(&svm, &anchor_counter.pubkey()), 21);
+        assert_counter_eq(&svm, &native_counter.pubkey(), 11);
+    }
+
+    #[test]
+    fn test_anchor_increment_native_counter() {
+        let (mut svm, payer, native_program_id, anchor_program_id) = setup_svm();
+        let native_counter = init_counter(&mut svm, &payer, native_program_id, 5);
+
+        let instruction = Instruction::new_with_bytes(
+            anchor_program_id,
+            &AnchorIx::IncrementNativeCounter.to_data(),
+            vec![
+                AccountMeta::new(native_counter.pubkey(), false),
+                AccountMeta::new_readonly(native_program_id, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        let logs = run_and_get_logs(&mut svm, transaction);
+
+        // Anchor at the top level, the native program one CPI below it
+        assert!(logs.contains(&format!("Program {} invoke [1]", anchor_program_id)));
+        assert!(logs.contains(&format!("Program {} invoke [2]", native_program_id)));
+        assert!(logs
+            .iter()
+            .any(|log| log.contains("Successfully incremented native counter via CPI")));
+
+        assert_counter_eq(&svm, &native_counter.pubkey(), 6);
+    }
+
+    #[test]
+    fn test_assert_count_guards_without_writing() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter_keypair = init_counter(&mut svm, &payer, program_id, 7);
+
+        let assert_count = |svm: &mut LiteSVM, expected: u64| {
+            let data = borsh::to_vec(&CounterInstruction::AssertCount { expected }).unwrap();
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![AccountMeta::new_readonly(counter_keypair.pubkey(), false)],
+            );
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let result = svm
+                .send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err);
+            svm.expire_blockhash();
+            result
+        };
+
+        assert_count(&mut svm, 7).expect("Matching count should pass");
+
+        let err = assert_count(&mut svm, 8).expect_err("Mismatched count should fail");
+        assert_eq!(
+            err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::CasMismatch as u32)
+            )
+        );
+
+        assert_counter_eq(&svm, &counter_keypair.pubkey(), 7);
+    }
+
+    #[test]
+    fn test_instruction_data_sizes_native_vs_anchor() {
+        // Native: a 1-byte borsh variant index; Anchor: an 8-byte sha256 discriminator
+        let sizes = [
+            (
+                "increment",
+                borsh::to_vec(&CounterInstruction::IncrementCounter).unwrap(),
+                AnchorIx::IncrementCounter.to_data(),
+            ),
+            (
+                "initialize",
+                borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 42 })
+                    .unwrap(),
+                AnchorIx::InitializeCounter { initial_value: 42 }.to_data(),
+            ),
+        ];
+
+        println!("{:<12} {:>7} {:>7}", "instruction", "native", "anchor");
+        for (name, native, anchor) in &sizes {
+            println!("{:<12} {:>7} {:>7}", name, native.len(), anchor.len());
+        }
+
+        let lengths: Vec<(usize, usize)> = sizes
+            .iter()
+            .map(|(_, native, anchor)| (native.len(), anchor.len()))
+            .collect();
+        assert_eq!(lengths, [(1, 8), (1 + 8, 8 + 8)]);
+    }
+
+    #[test]
+    fn test_set_max_value_requires_authority() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let counter = init_counter(&mut svm, &payer, program_id, 3);
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+
+        // A cap at the current count would freeze the counter
+        let set_max_data = borsh::to_vec(&CounterInstruction::SetMaxValue { max_value: 3 })
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_max_data,
+            vec![
+                AccountMeta::new(counter.pubkey(), false),
+                AccountMeta::new(intruder.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[instruction], Some(&intruder.pubkey()));
+        let transaction = Transaction::new(&[&intruder], message, svm.latest_blockhash());
+        let err = svm
+            .send_transaction(transaction)
+            .expect_err("Non-authority SetMaxValue should be rejected");
+        assert_eq!(
+            err.err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            )
+        );
+        assert_eq!(read_counter(&svm, &counter.pubkey()).max_value, 0);
+    }
+
+    #[test]
+    fn test_increment_counter_rejects_whitelist_counter() {
+        let (mut svm, payer, program_id) = setup_native_svm();
+        let merkle_root = merkle::hash_leaf(&ProgramPubkey::new_unique());
+
+        let counter_keypair = Keypair::new();
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeWhitelistCounter {
+            initial_value: 0,
+            merkle_root,
+        })
+        .expect("Failed to serialize instruction");
+        let init_instruction = Instruction::new_with_bytes(
+            program_id,
+            &init_data,
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), true),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[init_instruction], Some(&payer.pubkey()));
+        let transaction =
+            Transaction::new(&[&payer, &counter_keypair], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Whitelist initialize should succeed");
+        let before = svm.get_account(&counter_keypair.pubkey()).unwrap();
+
+        // Plain IncrementCounter skips the Merkle proof, so it must not accept the
+        // whitelist counter at all: neither in place nor by migrating it first
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        for accounts in [
+            vec![AccountMeta::new(counter_keypair.pubkey(), false)],
+            vec![
+                AccountMeta::new(counter_keypair.pubkey(), false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        ] {
+            let instruction = Instruction::new_with_bytes(program_id, &increment_data, accounts);
+            let message = Message::new(&[instruction], Some(&payer.pubkey()));
+            let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+            let result = svm
+                .send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err);
+            assert_eq!(
+                result,
+                Err(TransactionError::InstructionError(
+                    0,
+                    InstructionError::InvalidAccountData
+                ))
+            );
+            svm.expire_blockhash();
+        }
+
+        let account = svm.get_account(&counter_keypair.pubkey()).unwrap();
+        assert_eq!(account.data(), before.data());
+        assert_eq!(account.lamports(), before.lamports());
+        let counter = WhitelistCounterAccount::try_from_slice(account.data()).unwrap();
+        assert_eq!(counter.count, 0);
+        assert_eq!(counter.merkle_root, merkle_root);
+    }
+
+    #[test]
+    fn test_claim_authority_on_legacy_counter() {
+        use solana_sdk::account::Account;
+
+        let (mut svm, payer, program_id) = setup_native_svm();
+
+        // Plant a counter with the original 8-byte layout, created by its own keypair
+        let counter_keypair = Keypair::new();
+        let counter = counter_keypair.pubkey();
+        svm.set_account(
+            counter,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(CounterAccount::LEGACY_LEN),
+                data: 5u64.to_le_bytes().to_vec(),
+                owner: program_id,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let claim = |svm: &mut LiteSVM, authority: &Keypair, counter_signs: bool| {
+            let data = borsh::to_vec(&CounterInstruction::ClaimAuthority)
+                .expect("Failed to serialize instruction");
+            let instruction = Instruction::new_with_bytes(
+                program_id,
+                &data,
+                vec![
+                    AccountMeta::new(counter, counter_signs),
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                ],
+            );
+            let message = Message::new(&[instruction], Some(&authority.pubkey()));
+            let transaction = if counter_signs {
+                Transaction::new(
+                    &[authority, &counter_keypair],
+                    message,
+                    svm.latest_blockhash(),
+                )
+            } else {
+                Transaction::new(&[authority], message, svm.latest_blockhash())
+            };
+            svm.send_transaction(transaction)
+                .map(|_| ())
+                .map_err(|failed| failed.err)
+        };
+
+        // ===== Without the counter's signature, anyone could claim it =====
+        let intruder = Keypair::new();
+        svm.airdrop(&intruder.pubkey(), 1_000_000_000).unwrap();
+        assert_eq!(
+            claim(&mut svm, &intruder, false),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::MissingRequiredSignature
+            ))
+        );
+
+        // ===== The creator claims it, migrating it to the current layout =====
+        assert_eq!(claim(&mut svm, &payer, true), Ok(()));
+        let account = svm.get_account(&counter).unwrap();
+        assert_eq!(account.data().len(), CounterAccount::LEN);
+        let claimed = read_counter(&svm, &counter);
+        assert_eq!(claimed.count, 5);
+        assert_eq!(claimed.authority.to_bytes(), payer.pubkey().to_bytes());
+
+        // ===== Authority-gated instructions now work on it =====
+        let set_step_data = borsh::to_vec(&CounterInstruction::SetStep { step: 3 })
+            .expect("Failed to serialize instruction");
+        let set_step_instruction = Instruction::new_with_bytes(
+            program_id,
+            &set_step_data,
+            vec![
+                AccountMeta::new(counter, false),
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+        );
+        let message = Message::new(&[set_step_instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[&payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("SetStep by the claimed authority should succeed");
+        assert_eq!(read_counter(&svm, &counter).step, 3);
+
+        // ===== Claiming is one-time, even with the counter's signature =====
+        svm.expire_blockhash();
+        assert_eq!(
+            claim(&mut svm, &intruder, true),
+            Err(TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(CounterError::InvalidAuthority as u32)
+            ))
+        );
+    }
+}
+
+/// Processor tests that call `processor::process` directly, without building or
+/// deploying the program
+#[cfg(test)]
+mod processor_test {
+    use super::*;
+    use borsh::BorshDeserialize;
+    use solana_program::{
+        clock::Clock, instruction::Instruction, program_error::ProgramError,
+        program_stubs::SyscallStubs, rent::Rent,
+    };
+
+    // System Program ID - well-known constant "11111111111111111111111111111111"
+    const SYSTEM_PROGRAM_ID: Pubkey = solana_program::pubkey!("11111111111111111111111111111111");
+
+    const NATIVE_TEST_TIMESTAMP: i64 = 1_700_000_000;
+
+    /// Syscall stubs so the processor can run natively
+    ///
+    /// Rent and Clock return fixed values, and the only CPI supported is the System
+    /// Program's `create_account`, which is applied directly to the `AccountInfo`s.
+    struct NativeSyscallStubs;
+
+    impl SyscallStubs for NativeSyscallStubs {
+        fn sol_get_rent_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe { *(var_addr as *mut Rent) = Rent::default() };
+            0
+        }
+
+        fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+            unsafe {
+                *(var_addr as *mut Clock) = Clock {
+                    unix_timestamp: NATIVE_TEST_TIMESTAMP,
+                    ..Clock::default()
+                }
+            };
+            0
+        }
+
+        fn sol_invoke_signed(
+            &self,
+            instruction: &Instruction,
+            account_infos: &[AccountInfo],
+            _signers_seeds: &[&[&[u8]]],
+        ) -> ProgramResult {
+            // create_account data: [tag: u32 | lamports: u64 | space: u64 | owner: Pubkey]
+            assert_eq!(instruction.program_id, SYSTEM_PROGRAM_ID);
+            let data = &instruction.data;
+            assert_eq!(u32::from_le_bytes(data[0..4].try_into().unwrap()), 0);
+            let lamports = u64::from_le_bytes(data[4..12].try_into().unwrap());
+            let space = u64::from_le_bytes(data[12..20].try_into().unwrap()) as usize;
+
+            let find = |key: &Pubkey| {
+                account_infos
+                    .iter()
+                    .find(|account| account.key == key)
+                    .expect("CPI account missing from account infos")
+            };
+            let payer = find(&instruction.accounts[0].pubkey);
+            let new_account = find(&instruction.accounts[1].pubkey);
+
+            **payer.lamports.borrow_mut() -= lamports;
+            **new_account.lamports.borrow_mut() += lamports;
+            *new_account.data.borrow_mut() = Box::leak(vec![0u8; space].into_boxed_slice());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_process_initialize_and_increment() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let program_id = crate::ID;
+        let counter_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+
+        // ===== Initialize =====
+        let mut counter_lamports = 0;
+        let mut counter_data = [];
+        let counter = AccountInfo::new(
+            &counter_key,
+            true,
+            true,
+            &mut counter_lamports,
+            &mut counter_data,
+            &SYSTEM_PROGRAM_ID,
+            false,
+            0,
+        );
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_data = [];
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &SYSTEM_PROGRAM_ID,
+            false,
+            0,
+        );
+        let mut system_lamports = 0;
+        let mut system_data = [];
+        let system = AccountInfo::new(
+            &SYSTEM_PROGRAM_ID,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &SYSTEM_PROGRAM_ID,
+            true,
+            0,
+        );
+
+        let init_data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value: 41 })
+            .expect("Failed to serialize instruction");
+        processor::process(&program_id, &[counter.clone(), payer, system], &init_data)
+            .expect("Initialize should succeed");
+
+        assert_eq!(
+            counter.lamports(),
+            Rent::default().minimum_balance(CounterAccount::LEN)
+        );
+        let initialized = CounterAccount::load(&counter.data.borrow()).unwrap();
+        assert_eq!(initialized.count, 41);
+
+        // ===== Increment =====
+        // The emulated create_account doesn't reassign the owner, so hand the
+        // resulting data to an account owned by the program
+        let mut owned_lamports = counter.lamports();
+        let mut owned_data = counter.data.borrow().to_vec();
+        let owned_counter = AccountInfo::new(
+            &counter_key,
+            false,
+            true,
+            &mut owned_lamports,
+            &mut owned_data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        processor::process(
+            &program_id,
+            std::slice::from_ref(&owned_counter),
+            &increment_data,
+        )
+        .expect("Increment should succeed");
+
+        let incremented = CounterAccount::load(&owned_counter.data.borrow()).unwrap();
+        assert_eq!(incremented.count, 42);
+        assert_eq!(incremented.last_updated, NATIVE_TEST_TIMESTAMP);
+    }
+
+    #[test]
+    fn test_process_increment_rejects_foreign_owner() {
+        let program_id = crate::ID;
+        let counter_key = Pubkey::new_unique();
+
+        let mut lamports = 1_000_000;
+        let mut data = borsh::to_vec(&CounterAccount::default()).unwrap();
+        let counter = AccountInfo::new(
+            &counter_key,
+            false,
+            true,
+            &mut lamports,
+            &mut data,
+            &SYSTEM_PROGRAM_ID,
+            false,
+            0,
+        );
+
+        let increment_data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let result = processor::process(&program_id, &[counter], &increment_data);
+        assert_eq!(result, Err(ProgramError::IncorrectProgramId));
+    }
+
+    /// Run one instruction against a program-owned counter holding `data`
+    fn process_on_counter(data: &mut [u8], instruction: &CounterInstruction) -> ProgramResult {
+        let program_id = crate::ID;
+        let counter_key = Pubkey::new_unique();
+        let mut lamports = Rent::default().minimum_balance(data.len());
+        let counter = AccountInfo::new(
+            &counter_key,
+            false,
+            true,
+            &mut lamports,
+            data,
+            &program_id,
+            false,
+            0,
+        );
+
+        let instruction_data = borsh::to_vec(instruction).expect("Failed to serialize instruction");
+        processor::process(&program_id, &[counter], &instruction_data)
+    }
+
+    // Like `process_on_counter`, with `signer` passed as a signing second account
+    fn process_on_counter_as(
+        data: &mut [u8],
+        instruction: &CounterInstruction,
+        signer: &Pubkey,
+    ) -> ProgramResult {
+        let program_id = crate::ID;
+        let counter_key = Pubkey::new_unique();
+        let mut lamports = Rent::default().minimum_balance(data.len());
+        let counter = AccountInfo::new(
+            &counter_key,
+            false,
+            true,
+            &mut lamports,
+            data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut signer_lamports = 0;
+        let mut signer_data = [];
+        let system_program = solana_program::system_program::ID;
+        let signer = AccountInfo::new(
+            signer,
+            true,
+            false,
+            &mut signer_lamports,
+            &mut signer_data,
+            &system_program,
+            false,
+            0,
+        );
+
+        let instruction_data = borsh::to_vec(instruction).expect("Failed to serialize instruction");
+        processor::process(&program_id, &[counter, signer], &instruction_data)
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_increment_is_inverse_of_decrement(value in 1..=u64::MAX) {
+            solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+            let mut data = borsh::to_vec(&CounterAccount {
+                count: value,
+                ..CounterAccount::default()
+            })
+            .unwrap();
+
+            process_on_counter(&mut data, &CounterInstruction::DecrementCounter).unwrap();
+            proptest::prop_assert_eq!(
+                CounterAccount::try_from_slice(&data).unwrap().count,
+                value - 1
+            );
+
+            process_on_counter(&mut data, &CounterInstruction::IncrementCounter).unwrap();
+            proptest::prop_assert_eq!(CounterAccount::try_from_slice(&data).unwrap().count, value);
+
+            // Decrementing a zero counter always fails and leaves it untouched
+            let mut zero = borsh::to_vec(&CounterAccount::default()).unwrap();
+            proptest::prop_assert_eq!(
+                process_on_counter(&mut zero, &CounterInstruction::DecrementCounter),
+                Err(CounterError::CounterUnderflow.into())
+            );
+            proptest::prop_assert_eq!(CounterAccount::try_from_slice(&zero).unwrap().count, 0);
+        }
+    }
+
+    #[test]
+    fn test_process_increment_overflow_is_counter_overflow() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        // A large step pushes the count past u64::MAX
+        let mut data = borsh::to_vec(&CounterAccount {
+            count: u64::MAX - 5,
+            step: 10,
+            ..CounterAccount::default()
+        })
+        .unwrap();
+
+        assert_eq!(
+            process_on_counter(&mut data, &CounterInstruction::IncrementCounter),
+            Err(ProgramError::Custom(CounterError::CounterOverflow as u32))
+        );
+        assert_eq!(
+            CounterAccount::try_from_slice(&data).unwrap().count,
+            u64::MAX - 5
+        );
+    }
+
+    #[cfg(feature = "anchor-cpi")]
+    #[test]
+    fn test_anchor_increment_cpi_context_wires_accounts() {
+        use anchor_lang::ToAccountMetas;
+
+        let mut lamports = [0u64; 3];
+        let mut data: [Vec<u8>; 3] = Default::default();
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let owner = Pubkey::default();
+        let accounts: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, true, lamports, data, &owner, false, 0)
+            })
+            .collect();
+
+        let ctx = processor::anchor_increment_cpi_context(&accounts[0], &accounts[1], &accounts[2]);
+        assert_eq!(ctx.accounts.counter.key, &keys[0]);
+        assert_eq!(ctx.accounts.authority.key, &keys[1]);
+        assert_eq!(ctx.program.key, &keys[2]);
+        assert!(ctx.signer_seeds.is_empty());
+
+        // The metas Anchor will send: the counter writable, the authority signing
+        let metas = ctx.accounts.to_account_metas(None);
+        assert_eq!(metas.len(), 2);
+        assert_eq!((metas[0].pubkey, metas[0].is_writable), (keys[0], true));
+        assert_eq!((metas[1].pubkey, metas[1].is_signer), (keys[1], true));
+    }
+
+    #[cfg(not(feature = "anchor-cpi"))]
+    #[test]
+    fn test_process_anchor_cpi_without_feature_is_feature_disabled() {
+        let mut lamports = [0u64; 3];
+        let mut data: [Vec<u8>; 3] = Default::default();
+        let keys = [
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        ];
+        let owner = Pubkey::default();
+        let accounts: Vec<AccountInfo> = keys
+            .iter()
+            .zip(lamports.iter_mut())
+            .zip(data.iter_mut())
+            .map(|((key, lamports), data)| {
+                AccountInfo::new(key, false, true, lamports, data, &owner, false, 0)
+            })
+            .collect();
+
+        let instruction_data = borsh::to_vec(&CounterInstruction::IncrementAnchorCounter).unwrap();
+        assert_eq!(
+            processor::process(&crate::ID, &accounts, &instruction_data),
+            Err(CounterError::FeatureDisabled.into())
+        );
+    }
+
+    #[cfg(feature = "debug-logs")]
+    #[test]
+    fn test_debug_logs_are_json() {
+        let line = crate::processor::format_count_log("increment", "Counter incremented to", 101);
+        let event: serde_json::Value = serde_json::from_str(&line).expect("Log should be JSON");
+        assert_eq!(event["op"], "increment");
+        assert_eq!(event["new"], 101);
+    }
+
+    #[cfg(not(feature = "debug-logs"))]
+    #[test]
+    fn test_count_logs_are_prose_by_default() {
+        assert_eq!(
+            crate::processor::format_count_log("increment", "Counter incremented to", 101),
+            "Counter incremented to: 101"
+        );
+    }
+
+    #[test]
+    fn test_zero_copy_increment_matches_borsh() {
+        use crate::processor::{increment_borsh, increment_zero_copy};
+
+        let counters = [
+            CounterAccount::new(41),
+            CounterAccount::builder().count(10).step(5).build().unwrap(),
+            CounterAccount::builder()
+                .count(8)
+                .step(2)
+                .max_value(9)
+                .build()
+                .unwrap(),
+            CounterAccount {
+                enabled: false,
+                ..CounterAccount::new(3)
+            },
+            CounterAccount::new(u64::MAX),
+        ];
+
+        for counter in counters {
+            let mut borsh_data = borsh::to_vec(&counter).unwrap();
+            let mut zero_copy_data = borsh_data.clone();
+
+            let borsh_result = increment_borsh(&mut borsh_data, NATIVE_TEST_TIMESTAMP);
+            let zero_copy_result = increment_zero_copy(&mut zero_copy_data, NATIVE_TEST_TIMESTAMP);
+
+            assert_eq!(zero_copy_result, borsh_result, "Result for {:?}", counter);
+            assert_eq!(zero_copy_data, borsh_data, "Data for {:?}", counter);
+        }
+
+        // The view reads the same fields borsh does
+        let counter = CounterAccount::builder().count(7).step(2).build().unwrap();
+        let data = borsh::to_vec(&counter).unwrap();
+        let view = CounterAccountPod::from_bytes(&data).unwrap();
+        assert_eq!({ view.count }, 7);
+        assert_eq!(view.increment_step(), 2);
+
+        // Legacy layouts are too short for the view
+        assert!(CounterAccountPod::from_bytes(&data[..CounterAccount::LEGACY_LEN]).is_none());
+    }
+
+    // Increment a counter at u64::MAX that handles overflow as `behavior`
+    fn increment_at_max(behavior: OverflowBehavior) -> (ProgramResult, u64) {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let counter = CounterAccount::builder()
+            .count(u64::MAX)
+            .overflow_behavior(behavior)
+            .build()
+            .unwrap();
+        let mut data = borsh::to_vec(&counter).unwrap();
+
+        let result = process_on_counter(&mut data, &CounterInstruction::IncrementCounter);
+        (result, CounterAccount::load(&data).unwrap().count)
+    }
+
+    #[test]
+    fn test_overflow_behavior_error_rejects_increment() {
+        assert_eq!(
+            increment_at_max(OverflowBehavior::Error),
+            (Err(CounterError::CounterOverflow.into()), u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_overflow_behavior_wrap_goes_back_to_zero() {
+        assert_eq!(increment_at_max(OverflowBehavior::Wrap), (Ok(()), 0));
+    }
+
+    #[test]
+    fn test_overflow_behavior_saturate_stays_at_max() {
+        assert_eq!(
+            increment_at_max(OverflowBehavior::Saturate),
+            (Ok(()), u64::MAX)
+        );
+    }
+
+    // Apply AdjustCounter as its authority to a counter at `count`, returning the result and the new count
+    fn adjust(count: u64, delta: i64) -> (ProgramResult, u64) {
+        let authority = Pubkey::new_unique();
+        let counter = CounterAccount {
+            authority,
+            ..CounterAccount::new(count)
+        };
+        adjust_as(counter, delta, &authority)
+    }
+
+    fn adjust_as(counter: CounterAccount, delta: i64, signer: &Pubkey) -> (ProgramResult, u64) {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let mut data = borsh::to_vec(&counter).unwrap();
+        let result = process_on_counter_as(
+            &mut data,
+            &CounterInstruction::AdjustCounter { delta },
+            signer,
+        );
+        (result, CounterAccount::load(&data).unwrap().count)
+    }
+
+    #[test]
+    fn test_adjust_counter_positive_delta() {
+        assert_eq!(adjust(10, 5), (Ok(()), 15));
+        assert_eq!(
+            adjust(u64::MAX - 1, 2),
+            (Err(CounterError::CounterOverflow.into()), u64::MAX - 1)
+        );
+    }
+
+    #[test]
+    fn test_adjust_counter_negative_delta() {
+        assert_eq!(adjust(10, -4), (Ok(()), 6));
+        assert_eq!(adjust(u64::MAX, i64::MIN), (Ok(()), u64::MAX - (1 << 63)));
+    }
+
+    #[test]
+    fn test_adjust_counter_underflow_below_zero() {
+        assert_eq!(
+            adjust(3, -4),
+            (Err(CounterError::CounterUnderflow.into()), 3)
+        );
+    }
+
+    #[test]
+    fn test_adjust_counter_requires_authority() {
+        let authority = Pubkey::new_unique();
+        let counter = CounterAccount {
+            count: 10,
+            authority,
+            ..CounterAccount::default()
+        };
+
+        assert_eq!(adjust_as(counter.clone(), -10, &authority), (Ok(()), 0));
+        assert_eq!(
+            adjust_as(counter.clone(), -10, &Pubkey::new_unique()),
+            (Err(CounterError::InvalidAuthority.into()), 10)
+        );
+        // The zeroed key is not a stand-in for the authority
+        assert_eq!(
+            adjust_as(counter, 5, &Pubkey::default()),
+            (Err(CounterError::InvalidAuthority.into()), 10)
+        );
+    }
+
+    #[test]
+    fn test_adjust_counter_respects_max_value() {
+        let authority = Pubkey::new_unique();
+        let counter = CounterAccount {
+            count: 10,
+            max_value: 12,
+            authority,
+            ..CounterAccount::default()
+        };
+
+        assert_eq!(adjust_as(counter.clone(), 2, &authority), (Ok(()), 12));
+        assert_eq!(
+            adjust_as(counter, 3, &authority),
+            (Err(CounterError::CounterOverflow.into()), 10)
+        );
+    }
+
+    #[test]
+    fn test_increment_saturating_stops_at_max_value() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let mut data = borsh::to_vec(&CounterAccount {
+            count: 9,
+            max_value: 10,
+            ..CounterAccount::default()
+        })
+        .unwrap();
+
+        // 9 -> 10 reaches the cap, and further increments stay pinned there
+        for _ in 0..3 {
+            process_on_counter(&mut data, &CounterInstruction::IncrementCounterSaturating)
+                .expect("Saturating increment should never fail");
+        }
+        assert_eq!(CounterAccount::load(&data).unwrap().count, 10);
+    }
+
+    #[test]
+    fn test_increment_rejects_other_account_types() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let whitelist = WhitelistCounterAccount {
+            discriminator: WhitelistCounterAccount::DISCRIMINATOR,
+            count: 5,
+            merkle_root: [7; 32],
+        };
+        let stats = StatsAccount {
+            discriminator: StatsAccount::DISCRIMINATOR,
+            total_increments: 3,
+            bump: 255,
+        };
+        // 24 bytes, the size of a released counter layout, so only the
+        // discriminator gives it away
+        let big = BigCounterAccount {
+            discriminator: BigCounterAccount::DISCRIMINATOR,
+            count: 9,
+        };
+
+        for original in [
+            borsh::to_vec(&whitelist).unwrap(),
+            borsh::to_vec(&stats).unwrap(),
+            borsh::to_vec(&big).unwrap(),
+        ] {
+            let mut data = original.clone();
+            assert_eq!(
+                process_on_counter(&mut data, &CounterInstruction::IncrementCounter),
+                Err(ProgramError::InvalidAccountData)
+            );
+            assert_eq!(data, original);
+        }
+    }
+
+    #[test]
+    fn test_initialize_idempotent_rejects_other_account_types() {
+        let program_id = crate::ID;
+        let stats_key = Pubkey::new_unique();
+        let payer_key = Pubkey::new_unique();
+
+        let mut stats_data = borsh::to_vec(&StatsAccount {
+            discriminator: StatsAccount::DISCRIMINATOR,
+            total_increments: 3,
+            bump: 255,
+        })
+        .unwrap();
+        let mut stats_lamports = Rent::default().minimum_balance(stats_data.len());
+        let stats = AccountInfo::new(
+            &stats_key,
+            true,
+            true,
+            &mut stats_lamports,
+            &mut stats_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut payer_lamports = 1_000_000_000;
+        let mut payer_data = [];
+        let payer = AccountInfo::new(
+            &payer_key,
+            true,
+            true,
+            &mut payer_lamports,
+            &mut payer_data,
+            &SYSTEM_PROGRAM_ID,
+            false,
+            0,
+        );
+        let mut system_lamports = 0;
+        let mut system_data = [];
+        let system_program = AccountInfo::new(
+            &SYSTEM_PROGRAM_ID,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &SYSTEM_PROGRAM_ID,
+            true,
+            0,
+        );
+
+        let instruction_data =
+            borsh::to_vec(&CounterInstruction::InitializeCounterIdempotent { initial_value: 7 })
+                .expect("Failed to serialize instruction");
+        let result = processor::process(
+            &program_id,
+            &[stats, payer, system_program],
+            &instruction_data,
+        );
+        assert_eq!(result, Err(CounterError::AlreadyInitialized.into()));
+    }
+
+    #[test]
+    fn test_process_claim_authority_on_pda_counter() {
+        solana_program::program_stubs::set_syscall_stubs(Box::new(NativeSyscallStubs));
+
+        let program_id = crate::ID;
+        let authority_key = Pubkey::new_unique();
+        let (counter_key, _bump) = find_counter_pda(&authority_key);
+
+        // A current-layout PDA counter whose authority was never set
+        let mut counter_data = borsh::to_vec(&CounterAccount::new(4)).unwrap();
+        let mut counter_lamports = Rent::default().minimum_balance(counter_data.len());
+        let counter = AccountInfo::new(
+            &counter_key,
+            false,
+            true,
+            &mut counter_lamports,
+            &mut counter_data,
+            &program_id,
+            false,
+            0,
+        );
+        let mut authority_lamports = 1_000_000_000;
+        let mut authority_data = [];
+        let authority = AccountInfo::new(
+            &authority_key,
+            true,
+            true,
+            &mut authority_lamports,
+            &mut authority_data,
+            &SYSTEM_PROGRAM_ID,
+            false,
+            0,
+        );
+        let mut system_lamports = 0;
+        let mut system_data = [];
+        let system_program = AccountInfo::new(
+            &SYSTEM_PROGRAM_ID,
+            false,
+            false,
+            &mut system_lamports,
+            &mut system_data,
+            &SYSTEM_PROGRAM_ID,
+            true,
+            0,
+        );
+
+        let instruction_data = borsh::to_vec(&CounterInstruction::ClaimAuthority)
+            .expect("Failed to serialize instruction");
+        let accounts = [counter, authority, system_program];
+        processor::process(&program_id, &accounts, &instruction_data).unwrap();
+
+        let claimed = CounterAccount::load(&accounts[0].data.borrow()).unwrap();
+        assert_eq!(claimed.authority, authority_key);
+        assert_eq!(claimed.count, 4);
+
+        // The PDA derivation only proves ownership once
+        assert_eq!(
+            processor::process(&program_id, &accounts, &instruction_data),
+            Err(CounterError::InvalidAuthority.into())
+        );
+    }
 }
@@ -0,0 +1,36 @@
+//! Keccak Merkle proofs for `IncrementIfWhitelisted`
+//!
+//! Pairs are hashed in sorted order, so a proof is just the sibling hashes from
+//! the leaf up to the root, with no left/right flags. Each leaf is the hash of a
+//! whitelisted wallet address, and the instruction derives it from the signer.
+//!
+//! Leaves are hashed with a `0x00` prefix and internal nodes with `0x01`, so an
+//! internal node can never be passed off as a leaf (a second-preimage proof).
+
+use solana_keccak_hasher::hashv;
+use solana_program::pubkey::Pubkey;
+
+/// Domain prefix for leaf hashes
+const LEAF_PREFIX: &[u8] = &[0x00];
+
+/// Domain prefix for internal node hashes
+const NODE_PREFIX: &[u8] = &[0x01];
+
+/// Hash a whitelisted wallet address into its leaf
+pub fn hash_leaf(key: &Pubkey) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, key.as_ref()]).to_bytes()
+}
+
+/// Hash two nodes into their parent, smaller one first
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, first, second]).to_bytes()
+}
+
+/// Whether `proof` leads from `leaf` to `root`
+pub fn verify_proof(root: &[u8; 32], leaf: &[u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(*leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
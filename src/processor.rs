@@ -1,15 +1,28 @@
-use crate::{instructions::CounterInstruction, state::CounterAccount};
+use crate::{
+    cpi_utils::invoke_logged,
+    errors::CounterError,
+    instructions::{AnchorIx, CounterInstruction},
+    merkle,
+    state::{
+        AnchorCounterView, BigCounterAccount, CommitmentAccount, CounterAccount, CounterAccountPod,
+        Discriminator, HistoryCounterAccount, IndexedCounterAccount, NamedCounterAccount,
+        OverflowBehavior, StatsAccount, WhitelistCounterAccount, COMMITMENT_SEED, COUNTER_SEED,
+        HISTORY_LEN, STATS_SEED,
+    },
+};
+#[cfg(feature = "anchor-cpi")]
 use anchor_lang::ToAccountInfo; // Required for Anchor CPI client
-use borsh::{BorshDeserialize, BorshSerialize};
+use borsh::BorshSerialize;
 use solana_program::{
     account_info::{next_account_info, AccountInfo},
+    compute_units::sol_remaining_compute_units,
     entrypoint::ProgramResult,
     msg,
-    program::invoke,
+    program::{invoke, invoke_signed, set_return_data},
     program_error::ProgramError,
     pubkey::Pubkey,
     system_instruction,
-    sysvar::{rent::Rent, Sysvar},
+    sysvar::{clock::Clock, rent::Rent, Sysvar},
 };
 
 pub fn process(
@@ -17,51 +30,675 @@ pub fn process(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    // Unpack instruction data
-    let instruction = CounterInstruction::try_from_slice(instruction_data)
-        .map_err(|_| ProgramError::InvalidInstructionData)?;
+    // Unpack instruction data (opcode first, then the borsh payload)
+    let instruction = CounterInstruction::unpack(instruction_data)?;
 
-    // Match instruction type
-    match instruction {
-        CounterInstruction::InitializeCounter { initial_value } => {
-            process_initialize_counter(program_id, accounts, initial_value)?
-        }
-        CounterInstruction::IncrementCounter => process_increment_counter(program_id, accounts)?,
-        CounterInstruction::IncrementAnchorCounter => process_increment_anchor_counter(accounts)?,
-        CounterInstruction::IncrementAnchorCounterRaw => {
-            process_increment_anchor_counter_raw(accounts)?
-        }
-        CounterInstruction::IncrementCounterSelfCpi => {
-            process_increment_counter_self_cpi(program_id, accounts)?
-        }
-        CounterInstruction::IncrementCounterCodamaClient => {
-            process_increment_counter_codama_client(program_id, accounts)?
+    // `unpack` succeeded, so the opcode is present and in range
+    HANDLERS[instruction_data[0] as usize](program_id, accounts, instruction)
+}
+
+/// Handler for one instruction, given the decoded instruction it was dispatched for
+pub(crate) type Handler = fn(&Pubkey, &[AccountInfo], CounterInstruction) -> ProgramResult;
+
+// Build a `Handler` that destructures `$variant` and runs `$handle` with its fields
+//
+// Any other variant is rejected, so a handler filed under the wrong opcode fails
+// loudly instead of running.
+macro_rules! handler {
+    (
+        $variant:ident $({ $($field:ident),* })?,
+        |$program_id:pat_param, $accounts:pat_param| $handle:expr
+    ) => {
+        |$program_id, $accounts, instruction| match instruction {
+            CounterInstruction::$variant $({ $($field),* })? => $handle,
+            _ => Err(ProgramError::InvalidInstructionData),
         }
     };
+}
+
+/// Handlers indexed by opcode
+///
+/// Sized by `CounterInstruction::MAX_OPCODE`, so appending a variant without a
+/// handler does not compile.
+pub(crate) const HANDLERS: [Handler; CounterInstruction::MAX_OPCODE as usize + 1] = [
+    handler!(
+        InitializeCounter { initial_value },
+        |program_id, accounts| process_initialize_counter(program_id, accounts, initial_value)
+    ),
+    handler!(IncrementCounter, |program_id, accounts| {
+        process_increment_counter(program_id, accounts)
+    }),
+    handler!(IncrementAnchorCounter, |_, accounts| {
+        process_increment_anchor_counter(accounts)
+    }),
+    handler!(IncrementAnchorCounterRaw, |_, accounts| {
+        process_increment_anchor_counter_raw(accounts)
+    }),
+    handler!(IncrementCounterSelfCpi, |program_id, accounts| {
+        process_increment_counter_self_cpi(program_id, accounts)
+    }),
+    handler!(IncrementCounterCodamaClient, |program_id, accounts| {
+        process_increment_counter_codama_client(program_id, accounts)
+    }),
+    handler!(IncrementCounterMultisig, |program_id, accounts| {
+        process_increment_counter_multisig(program_id, accounts)
+    }),
+    handler!(SetStep { step }, |program_id, accounts| process_set_step(
+        program_id, accounts, step
+    )),
+    handler!(DecrementCounterSaturating, |program_id, accounts| {
+        process_decrement_counter_saturating(program_id, accounts)
+    }),
+    handler!(
+        InitializeCounterCodamaClient { initial_value },
+        |program_id, accounts| process_initialize_via_codama(program_id, accounts, initial_value)
+    ),
+    handler!(
+        InitializeCounterPda { initial_value },
+        |program_id, accounts| process_initialize_counter_pda(program_id, accounts, initial_value)
+    ),
+    handler!(IncrementPdaCounterCodamaClient, |program_id, accounts| {
+        process_increment_pda_counter_codama_client(program_id, accounts)
+    }),
+    handler!(
+        InitializeBigCounter { initial_value },
+        |program_id, accounts| process_initialize_big_counter(program_id, accounts, initial_value)
+    ),
+    handler!(IncrementBigCounter, |program_id, accounts| {
+        process_increment_big_counter(program_id, accounts)
+    }),
+    handler!(
+        IncrementCounterRateLimited { min_interval_secs },
+        |program_id, accounts| process_increment_counter_rate_limited(
+            program_id,
+            accounts,
+            min_interval_secs
+        )
+    ),
+    handler!(SetMaxValue { max_value }, |program_id, accounts| {
+        process_set_max_value(program_id, accounts, max_value)
+    }),
+    handler!(DecrementCounter, |program_id, accounts| {
+        process_decrement_counter(program_id, accounts)
+    }),
+    handler!(IncrementAndTransfer, |program_id, accounts| {
+        process_increment_and_transfer(program_id, accounts)
+    }),
+    handler!(DecrementCounterCodamaClient, |program_id, accounts| {
+        process_decrement_counter_codama_client(program_id, accounts)
+    }),
+    handler!(
+        InitializeHistoryCounter { initial_value },
+        |program_id, accounts| process_initialize_history_counter(
+            program_id,
+            accounts,
+            initial_value
+        )
+    ),
+    handler!(IncrementHistoryCounter, |program_id, accounts| {
+        process_increment_history_counter(program_id, accounts)
+    }),
+    handler!(ResetCounter, |program_id, accounts| process_reset_counter(
+        program_id, accounts
+    )),
+    handler!(
+        TransferAuthority { new_authority },
+        |program_id, accounts| process_transfer_authority(program_id, accounts, new_authority)
+    ),
+    handler!(GetCount, |program_id, accounts| process_get_count(
+        program_id, accounts
+    )),
+    handler!(IncrementCounterSaturating, |program_id, accounts| {
+        process_increment_counter_saturating(program_id, accounts)
+    }),
+    handler!(
+        ToggleEnabled,
+        |program_id, accounts| process_toggle_enabled(program_id, accounts)
+    ),
+    handler!(
+        InitializeNamedCounter {
+            initial_value,
+            name
+        },
+        |program_id, accounts| process_initialize_named_counter(
+            program_id,
+            accounts,
+            initial_value,
+            name
+        )
+    ),
+    handler!(
+        InitializeCounterIndexed {
+            index,
+            initial_value
+        },
+        |program_id, accounts| process_initialize_counter_indexed(
+            program_id,
+            accounts,
+            index,
+            initial_value
+        )
+    ),
+    handler!(BatchIncrement, |program_id, accounts| {
+        process_batch_increment(program_id, accounts)
+    }),
+    handler!(SnapshotCounter, |program_id, accounts| {
+        process_snapshot_counter(program_id, accounts)
+    }),
+    handler!(RestoreCounter, |program_id, accounts| {
+        process_restore_counter(program_id, accounts)
+    }),
+    handler!(QueryRent, |_, accounts| process_query_rent(accounts)),
+    handler!(
+        LogMemo { message },
+        |program_id, accounts| process_log_memo(program_id, accounts, &message)
+    ),
+    handler!(CompareAndIncrement { expected }, |program_id, accounts| {
+        process_compare_and_increment(program_id, accounts, expected)
+    }),
+    handler!(CloseCounter, |program_id, accounts| process_close_counter(
+        program_id, accounts
+    )),
+    handler!(
+        InitializeCounterIdempotent { initial_value },
+        |program_id, accounts| process_initialize_counter_idempotent(
+            program_id,
+            accounts,
+            initial_value
+        )
+    ),
+    handler!(SetDelegate { delegate }, |program_id, accounts| {
+        process_set_delegate(program_id, accounts, delegate)
+    }),
+    handler!(IncrementAsDelegate, |program_id, accounts| {
+        process_increment_as_delegate(program_id, accounts)
+    }),
+    handler!(WithdrawSurplus, |program_id, accounts| {
+        process_withdraw_surplus(program_id, accounts)
+    }),
+    handler!(
+        InitializeWhitelistCounter {
+            initial_value,
+            merkle_root
+        },
+        |program_id, accounts| process_initialize_whitelist_counter(
+            program_id,
+            accounts,
+            initial_value,
+            merkle_root
+        )
+    ),
+    handler!(IncrementIfWhitelisted { proof }, |program_id, accounts| {
+        process_increment_if_whitelisted(program_id, accounts, &proof)
+    }),
+    handler!(CommitIncrement { hash }, |program_id, accounts| {
+        process_commit_increment(program_id, accounts, hash)
+    }),
+    handler!(RevealIncrement { nonce }, |program_id, accounts| {
+        process_reveal_increment(program_id, accounts, nonce)
+    }),
+    handler!(RecordEpoch, |program_id, accounts| process_record_epoch(
+        program_id, accounts
+    )),
+    handler!(
+        InitializeCounterWithSize {
+            initial_value,
+            reserved
+        },
+        |program_id, accounts| process_initialize_counter_with_size(
+            program_id,
+            accounts,
+            initial_value,
+            reserved
+        )
+    ),
+    handler!(AppendLog { entry }, |program_id, accounts| {
+        process_append_log(program_id, accounts, entry)
+    }),
+    handler!(
+        InitializeCounterWithOverflowBehavior {
+            initial_value,
+            overflow_behavior
+        },
+        |program_id, accounts| process_initialize_counter_with_overflow_behavior(
+            program_id,
+            accounts,
+            initial_value,
+            overflow_behavior
+        )
+    ),
+    handler!(AdjustCounter { delta }, |program_id, accounts| {
+        process_adjust_counter(program_id, accounts, delta)
+    }),
+    handler!(
+        InitAndIncrementSelfCpi { initial_value },
+        |program_id, accounts| process_init_and_increment_self_cpi(
+            program_id,
+            accounts,
+            initial_value
+        )
+    ),
+    handler!(InitializeStats, |program_id, accounts| {
+        process_initialize_stats(program_id, accounts)
+    }),
+    handler!(
+        InitializeCountersBatch {
+            count,
+            initial_value
+        },
+        |program_id, accounts| process_initialize_counters_batch(
+            program_id,
+            accounts,
+            count,
+            initial_value
+        )
+    ),
+    handler!(
+        IncrementCounterWithNonce { nonce },
+        |program_id, accounts| process_increment_counter_with_nonce(program_id, accounts, nonce)
+    ),
+    handler!(CopyCounter, |program_id, accounts| process_copy_counter(
+        program_id, accounts
+    )),
+    handler!(AssertCount { expected }, |program_id, accounts| {
+        process_assert_count(program_id, accounts, expected)
+    }),
+    handler!(ClaimAuthority, |program_id, accounts| {
+        process_claim_authority(program_id, accounts)
+    }),
+];
+
+/// Log a count change: prose by default, compact JSON with the `debug-logs` feature
+///
+/// `Counter incremented to: 101` becomes `{"op":"increment","new":101}`, which
+/// local log scrapers can parse without matching on wording.
+fn log_count(op: &str, prose: &str, new: u64) {
+    msg!("{}", format_count_log(op, prose, new));
+}
+
+pub(crate) fn format_count_log(op: &str, prose: &str, new: u64) -> String {
+    if cfg!(feature = "debug-logs") {
+        format!("{{\"op\":\"{}\",\"new\":{}}}", op, new)
+    } else {
+        format!("{}: {}", prose, new)
+    }
+}
+
+/// Reject more accounts than an instruction takes
+///
+/// This is an upper bound, not an exact count: too few are left to
+/// [`next_account`], which names the one that is missing (`MissingAccount`),
+/// and instructions with optional trailing accounts accept shorter lists.
+fn expect_accounts(accounts: &[AccountInfo], expected: usize) -> ProgramResult {
+    if accounts.len() > expected {
+        msg!(
+            "Error: Expected {} accounts, got {}",
+            expected,
+            accounts.len()
+        );
+        return Err(CounterError::UnexpectedAccountCount.into());
+    }
     Ok(())
 }
 
+/// Take the next account, failing with `MissingAccount` and its name if there is none
+///
+/// `next_account_info` alone fails with a bare `NotEnoughAccountKeys`.
+fn next_account<'a, 'b>(
+    accounts_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+    name: &str,
+) -> Result<&'a AccountInfo<'b>, ProgramError> {
+    next_account_info(accounts_iter).map_err(|_| {
+        msg!("Error: Missing {} account", name);
+        CounterError::MissingAccount.into()
+    })
+}
+
+/// Take the next optional account, treating a missing one or the program ID as absent
+///
+/// Codama clients pass the program ID in place of an omitted optional account,
+/// which keeps the accounts after it at fixed positions.
+fn optional_account<'a, 'b>(
+    accounts_iter: &mut std::slice::Iter<'a, AccountInfo<'b>>,
+    program_id: &Pubkey,
+) -> Option<&'a AccountInfo<'b>> {
+    accounts_iter
+        .next()
+        .filter(|account| account.key != program_id)
+}
+
 // Initialize a new counter account
 fn process_initialize_counter(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     initial_value: u64,
 ) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // The counter is a keypair account, so it signs for itself
+    create_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        initial_value,
+        &[],
+    )
+}
+
+// Initialize `count` keypair counters, listed after the payer and System Program
+fn process_initialize_counters_batch(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    count: u8,
+    initial_value: u64,
+) -> ProgramResult {
+    if count == 0 {
+        msg!("Error: Expected at least 1 counter");
+        return Err(ProgramError::InvalidArgument);
+    }
+    expect_accounts(accounts, 2 + count as usize)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    for _ in 0..count {
+        let counter_account = next_account(accounts_iter, "counter")?;
+        create_counter_account(
+            program_id,
+            counter_account,
+            payer_account,
+            system_program,
+            initial_value,
+            &[],
+        )?;
+    }
+
+    msg!("Initialized {} counters", count);
+    Ok(())
+}
+
+// Initialize a new counter at the PDA derived from ["counter", payer]
+fn process_initialize_counter_pda(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Manual PDA derivation and validation
+    let (counter_pda, bump) =
+        Pubkey::find_program_address(&[COUNTER_SEED, payer_account.key.as_ref()], program_id);
+    if counter_account.key != &counter_pda {
+        msg!("Error: Counter account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The program signs for the PDA with its seeds
+    create_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        initial_value,
+        &[&[COUNTER_SEED, payer_account.key.as_ref(), &[bump]]],
+    )
+}
+
+// Initialize one of a payer's numbered PDA counters
+fn process_initialize_counter_indexed(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    index: u64,
+    initial_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    let index_bytes = index.to_le_bytes();
+    let (counter_pda, bump) = Pubkey::find_program_address(
+        &[COUNTER_SEED, payer_account.key.as_ref(), &index_bytes],
+        program_id,
+    );
+    if counter_account.key != &counter_pda {
+        msg!(
+            "Error: Counter account is not the expected PDA for index {}",
+            index
+        );
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        IndexedCounterAccount::LEN,
+        &[&[
+            COUNTER_SEED,
+            payer_account.key.as_ref(),
+            &index_bytes,
+            &[bump],
+        ]],
+    )?;
+
+    let counter_data = IndexedCounterAccount {
+        discriminator: IndexedCounterAccount::DISCRIMINATOR,
+        count: initial_value,
+        index,
+        bump,
+    };
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+    counter_data.serialize(&mut account_data)?;
+
+    msg!(
+        "Counter {} initialized with value: {}",
+        index,
+        initial_value
+    );
+
+    Ok(())
+}
+
+// Initialize a counter unless it already exists, in which case leave it untouched
+fn process_initialize_counter_idempotent(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let counter_account = next_account(&mut accounts.iter(), "counter")?;
+
+    // Accounts owned by anyone else still fail with AlreadyInitialized below
+    if counter_account.owner == program_id && !counter_account.data_is_empty() {
+        // Another account type at this address is not "already initialized"
+        if CounterAccount::load(&counter_account.data.borrow()).is_err() {
+            msg!("Error: Account is initialized, but not as a counter");
+            return Err(CounterError::AlreadyInitialized.into());
+        }
+        msg!("Counter already initialized, leaving it unchanged");
+        return Ok(());
+    }
+
+    process_initialize_counter(program_id, accounts, initial_value)
+}
+
+// Initialize a counter in an account sized by the client, leaving room to grow
+fn process_initialize_counter_with_size(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+    reserved: u16,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Always leave room for the authority, or the rent could never be reclaimed
+    let space = (CounterAccount::LEGACY_LEN + reserved as usize).max(CounterAccount::LEN);
+    if space > CounterAccount::MAX_SPACE {
+        msg!(
+            "Error: {} bytes requested, at most {} allowed",
+            space,
+            CounterAccount::MAX_SPACE
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        space,
+        &[],
+    )?;
+
+    // The payer becomes the authority
+    let mut counter_data = CounterAccount::new(initial_value);
+    counter_data.authority = *payer_account.key;
+    counter_data.store(&mut counter_account.data.borrow_mut())?;
+
+    msg!(
+        "Counter initialized with value: {} ({} bytes)",
+        initial_value,
+        space
+    );
+    Ok(())
+}
+
+/// Create a counter account owned by this program and write its initial value
+///
+/// `signer_seeds` is empty for keypair counters and holds the PDA seeds otherwise.
+fn create_counter_account<'a>(
+    program_id: &Pubkey,
+    counter_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    initial_value: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        CounterAccount::LEN,
+        signer_seeds,
+    )?;
+
+    // Create a new CounterAccount struct with the initial value
+    // The payer becomes the counter's authority
+    let counter_data = CounterAccount::builder()
+        .count(initial_value)
+        .authority(*payer_account.key)
+        .build()?;
+
+    // Get a mutable reference to the counter account's data
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+
+    // Serialize the CounterAccount struct into the account's data
+    counter_data.serialize(&mut account_data)?;
+
+    log_count(
+        "initialize",
+        "Counter initialized with value",
+        initial_value,
+    );
+
+    Ok(())
+}
+
+// Initialize a counter whose increments wrap or saturate instead of failing on overflow
+fn process_initialize_counter_with_overflow_behavior(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+    overflow_behavior: u8,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
     let accounts_iter = &mut accounts.iter();
 
-    let counter_account = next_account_info(accounts_iter)?;
-    let payer_account = next_account_info(accounts_iter)?;
-    let system_program = next_account_info(accounts_iter)?;
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Checked up front so an unknown mode never reaches an account
+    let behavior = OverflowBehavior::try_from(overflow_behavior).inspect_err(|_| {
+        msg!("Error: Unknown overflow behavior {}", overflow_behavior);
+    })?;
+
+    create_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        initial_value,
+        &[],
+    )?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.overflow_behavior = behavior as u8;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter overflow behavior: {:?}", behavior);
+    Ok(())
+}
+
+/// Create an empty, rent-exempt account of `account_space` bytes owned by this program
+///
+/// Shared by every counter layout; the caller writes the initial data afterwards.
+fn allocate_counter_account<'a>(
+    program_id: &Pubkey,
+    counter_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    account_space: usize,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    // Verify the System Program account is the real System Program
+    if system_program.key != &solana_program::system_program::ID {
+        msg!("Error: Invalid system program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
 
-    // Size of our counter account
-    let account_space = 8; // u64 requires 8 bytes
+    // Paying for an account with its own lamports would corrupt the balance bookkeeping
+    if counter_account.key == payer_account.key {
+        msg!("Error: Counter account and payer must be different accounts");
+        return Err(CounterError::CounterIsPayer.into());
+    }
+
+    // Refuse to initialize an account that already exists
+    if counter_account.lamports() != 0 || !counter_account.data_is_empty() {
+        msg!("Error: Counter account is already initialized");
+        return Err(CounterError::AlreadyInitialized.into());
+    }
 
     // Calculate minimum balance for rent exemption
     let rent = Rent::get()?;
     let required_lamports = rent.minimum_balance(account_space);
 
     // Create the counter account
-    invoke(
+    invoke_signed(
         &system_instruction::create_account(
             payer_account.key,    // Account paying for the new account
             counter_account.key,  // Account to be created
@@ -74,50 +711,244 @@ fn process_initialize_counter(
             counter_account.clone(),
             system_program.clone(),
         ],
-    )?;
-
-    // Create a new CounterAccount struct with the initial value
-    let counter_data = CounterAccount {
-        count: initial_value,
-    };
-
-    // Get a mutable reference to the counter account's data
-    let mut account_data = &mut counter_account.data.borrow_mut()[..];
-
-    // Serialize the CounterAccount struct into the account's data
-    counter_data.serialize(&mut account_data)?;
-
-    msg!("Counter initialized with value: {}", initial_value);
-
-    Ok(())
+        signer_seeds,
+    )
 }
 
 // Update an existing counter's value
 fn process_increment_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    // The counter, then the optional payer, System Program and stats PDA, in that order
+    expect_accounts(accounts, 4)?;
     let accounts_iter = &mut accounts.iter();
-    let counter_account = next_account_info(accounts_iter)?;
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = optional_account(accounts_iter, program_id);
+    let system_program = optional_account(accounts_iter, program_id);
+    let stats_account = optional_account(accounts_iter, program_id);
 
     // Verify account ownership
     if counter_account.owner != program_id {
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    // Mutable borrow the account data
+    // Payer + System Program let older, shorter counters grow to the current
+    // layout so the timestamp can be recorded
+    match (payer_account, system_program) {
+        (Some(payer_account), Some(system_program)) => {
+            migrate_counter_account(counter_account, payer_account, system_program)?
+        }
+        (None, None) => {}
+        _ => {
+            msg!("Error: Payer and System Program must be passed together");
+            return Err(CounterError::MissingAccount.into());
+        }
+    }
+
+    // Flag, without failing, a balance that has drifted below rent-exemption
+    let minimum_balance = Rent::get()?.minimum_balance(counter_account.data_len());
+    if counter_account.lamports() < minimum_balance {
+        msg!(
+            "Warning: Counter is not rent-exempt ({} < {} lamports)",
+            counter_account.lamports(),
+            minimum_balance
+        );
+    }
+
+    // Record when this happened; unmigrated counters have no room for it
     let mut data = counter_account.data.borrow_mut();
+    let now = if data.len() >= CounterAccount::TIMESTAMP_LEN {
+        Clock::get()?.unix_timestamp
+    } else {
+        0
+    };
+
+    // Current-layout counters are updated in place; legacy ones go through borsh
+    let new_count = if data.len() >= CounterAccount::LEN {
+        increment_zero_copy(&mut data, now)?
+    } else {
+        increment_borsh(&mut data, now)?
+    };
+
+    drop(data);
+
+    if let Some(stats_account) = stats_account {
+        record_increment(program_id, stats_account)?;
+    }
+
+    // Expose the new value so callers (and CPI parents) can read it directly
+    set_return_data(&new_count.to_le_bytes());
+
+    log_count("increment", "Counter incremented to", new_count);
+    Ok(())
+}
+
+/// Apply one `IncrementCounter` to counter data in the current layout, in place
+///
+/// Reads and writes the fields through [`CounterAccountPod`] without running borsh.
+/// Returns the new count.
+pub(crate) fn increment_zero_copy(data: &mut [u8], now: i64) -> Result<u64, ProgramError> {
+    let counter =
+        CounterAccountPod::from_bytes_mut(data).ok_or(ProgramError::InvalidAccountData)?;
+
+    counter.count = next_count(
+        counter.enabled != 0,
+        counter.count,
+        counter.increment_step(),
+        counter.max_value,
+        counter.overflow_behavior,
+    )?;
+    counter.last_updated = now;
+
+    Ok(counter.count)
+}
 
+/// Apply one `IncrementCounter` by deserializing and re-serializing the counter
+///
+/// Accepts legacy layouts, which only record `now` once they have room for it.
+/// Returns the new count.
+pub(crate) fn increment_borsh(data: &mut [u8], now: i64) -> Result<u64, ProgramError> {
     // Deserialize the account data into our CounterAccount struct
-    let mut counter_data: CounterAccount = CounterAccount::try_from_slice(&data)?;
+    // (legacy counters are still accepted)
+    let mut counter_data = CounterAccount::try_from_slice_compat(data)?;
 
-    // Increment the counter value
-    counter_data.count = counter_data
-        .count
-        .checked_add(1)
-        .ok_or(ProgramError::InvalidAccountData)?;
+    counter_data.count = next_count(
+        counter_data.enabled,
+        counter_data.count,
+        counter_data.increment_step(),
+        counter_data.max_value,
+        counter_data.overflow_behavior,
+    )?;
+    if data.len() >= CounterAccount::TIMESTAMP_LEN {
+        counter_data.last_updated = now;
+    }
 
     // Serialize the updated counter data back into the account
-    counter_data.serialize(&mut &mut data[..])?;
+    counter_data.store(data)?;
+
+    Ok(counter_data.count)
+}
+
+// Count after adding `amount`, unless the counter is paused, overflows or passes its cap
+//
+// Passing `u64::MAX` follows the counter's `overflow_behavior`.
+fn next_count(
+    enabled: bool,
+    count: u64,
+    amount: u64,
+    max_value: u64,
+    overflow_behavior: u8,
+) -> Result<u64, ProgramError> {
+    ensure_enabled(enabled)?;
+
+    let new_count = match (
+        count.checked_add(amount),
+        OverflowBehavior::try_from(overflow_behavior),
+    ) {
+        (Some(new_count), _) => new_count,
+        (None, Ok(OverflowBehavior::Wrap)) => count.wrapping_add(amount),
+        (None, Ok(OverflowBehavior::Saturate)) => u64::MAX,
+        (None, _) => {
+            msg!("Error: Counter overflow adding {} to {}", amount, count);
+            return Err(CounterError::CounterOverflow.into());
+        }
+    };
+
+    ensure_within_max(new_count, max_value)?;
+    Ok(new_count)
+}
+
+// Paused counters refuse to move
+fn ensure_enabled(enabled: bool) -> ProgramResult {
+    if !enabled {
+        msg!("Error: Counter is disabled");
+        return Err(CounterError::CounterDisabled.into());
+    }
+    Ok(())
+}
+
+// Bounded counters refuse to go past their cap (0 means no cap)
+fn ensure_within_max(new_count: u64, max_value: u64) -> ProgramResult {
+    if max_value != 0 && new_count > max_value {
+        msg!("Error: Counter would exceed its max value {}", max_value);
+        return Err(CounterError::CounterOverflow.into());
+    }
+    Ok(())
+}
+
+/// Reject a CPI target that isn't a deployed program (or not the expected one)
+///
+/// Without this, a missing or wrong program only surfaces as an opaque runtime
+/// failure from inside the CPI.
+fn check_cpi_program(program: &AccountInfo, expected_id: Option<&Pubkey>) -> ProgramResult {
+    if !program.executable {
+        msg!("Error: {} is not an executable program", program.key);
+        return Err(CounterError::ProgramNotExecutable.into());
+    }
+
+    if let Some(expected_id) = expected_id {
+        if program.key != expected_id {
+            msg!(
+                "Error: Expected program {}, got {}",
+                expected_id,
+                program.key
+            );
+            return Err(CounterError::IncorrectProgramId.into());
+        }
+    }
+    Ok(())
+}
+
+/// Check a CPI target account is an Anchor `Counter` owned by `anchor_program`
+///
+/// Done before invoking, so the wrong account is rejected here with a clear
+/// message instead of by the Anchor program after the CPI has started.
+fn check_anchor_counter(
+    counter: &AccountInfo,
+    anchor_program: &AccountInfo,
+) -> Result<AnchorCounterView, ProgramError> {
+    if counter.owner != anchor_program.key {
+        msg!(
+            "Error: {} is not owned by the Anchor program {}",
+            counter.key,
+            anchor_program.key
+        );
+        return Err(ProgramError::IllegalOwner);
+    }
+
+    AnchorCounterView::unpack(&counter.data.borrow()).inspect_err(|_| {
+        msg!("Error: {} is not an Anchor Counter account", counter.key);
+    })
+}
+
+/// Compute units kept in reserve for each further counter in a batch
+///
+/// Comfortably above what one `IncrementCounter` (including its logs) costs.
+const BATCH_INCREMENT_MIN_COMPUTE_UNITS: u64 = 5_000;
+
+// Increment many counters, stopping early when the compute budget runs low
+fn process_batch_increment(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    if accounts.is_empty() {
+        msg!("Error: Expected at least 1 counter");
+        return Err(CounterError::UnexpectedAccountCount.into());
+    }
 
-    msg!("Counter incremented to: {}", counter_data.count);
+    let mut completed: u64 = 0;
+    for counter_account in accounts {
+        // Partial progress beats aborting the whole transaction
+        if sol_remaining_compute_units() < BATCH_INCREMENT_MIN_COMPUTE_UNITS {
+            msg!(
+                "Compute budget low, stopping after {} of {} counters",
+                completed,
+                accounts.len()
+            );
+            break;
+        }
+
+        process_increment_counter(program_id, std::slice::from_ref(counter_account))?;
+        completed += 1;
+    }
+
+    // Overrides the per-counter return data with the batch result
+    set_return_data(&completed.to_le_bytes());
     Ok(())
 }
 
@@ -129,27 +960,66 @@ fn process_increment_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> P
 /// - Auto-generated: No manual discriminator construction needed
 /// - Maintainable: Automatically updates when Anchor program changes
 /// - Error-resistant: Can't pass wrong accounts or wrong order
+///
+/// Requires the `anchor-cpi` feature (on by default).
+#[cfg(feature = "anchor-cpi")]
 fn process_increment_anchor_counter(accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
     let accounts_iter = &mut accounts.iter();
 
-    let anchor_counter_account = next_account_info(accounts_iter)?;
-    let anchor_authority_account = next_account_info(accounts_iter)?;
-    let anchor_program = next_account_info(accounts_iter)?;
+    let anchor_counter_account = next_account(accounts_iter, "anchor counter")?;
+    let anchor_authority_account = next_account(accounts_iter, "anchor authority")?;
+    let anchor_program = next_account(accounts_iter, "anchor program")?;
+
+    // The CPI client is built for this exact program
+    check_cpi_program(anchor_program, Some(&anchor_counter::ID))?;
+    check_anchor_counter(anchor_counter_account, anchor_program)?;
 
     msg!("Performing CPI to Anchor program using generated CPI client...");
 
     // ✅ Use Anchor's type-safe CPI client
     // This is auto-generated from the anchor-counter program when the 'cpi' feature is enabled
-    let cpi_program = anchor_program.to_account_info();
+    let cpi_ctx = anchor_increment_cpi_context(
+        anchor_counter_account,
+        anchor_authority_account,
+        anchor_program,
+    );
+    anchor_counter::cpi::increment_counter(cpi_ctx)?;
+
+    msg!("Successfully incremented Anchor counter via CPI (type-safe client)");
+    Ok(())
+}
+
+/// `CpiContext` for the Anchor program's `increment_counter`, from our three accounts
+///
+/// Shared by every CPI into it through the generated client, so the accounts are
+/// wired in one place.
+#[cfg(feature = "anchor-cpi")]
+pub(crate) fn anchor_increment_cpi_context<'info>(
+    anchor_counter_account: &AccountInfo<'info>,
+    anchor_authority_account: &AccountInfo<'info>,
+    anchor_program: &AccountInfo<'info>,
+) -> anchor_lang::context::CpiContext<
+    'static,
+    'static,
+    'static,
+    'info,
+    anchor_counter::cpi::accounts::IncrementCounter<'info>,
+> {
     let cpi_accounts = anchor_counter::cpi::accounts::IncrementCounter {
         counter: anchor_counter_account.to_account_info(),
         authority: anchor_authority_account.to_account_info(),
     };
-    let cpi_ctx = anchor_lang::context::CpiContext::new(cpi_program, cpi_accounts);
-    anchor_counter::cpi::increment_counter(cpi_ctx)?;
+    anchor_lang::context::CpiContext::new(anchor_program.to_account_info(), cpi_accounts)
+}
 
-    msg!("Successfully incremented Anchor counter via CPI (type-safe client)");
-    Ok(())
+/// Built without `anchor-cpi`: the typed Anchor CPI client isn't compiled in
+///
+/// `IncrementAnchorCounterRaw` still reaches the Anchor program without it.
+#[cfg(not(feature = "anchor-cpi"))]
+fn process_increment_anchor_counter(_accounts: &[AccountInfo]) -> ProgramResult {
+    msg!("Error: IncrementAnchorCounter requires the `anchor-cpi` feature");
+    Err(CounterError::FeatureDisabled.into())
 }
 
 /// Perform a CPI to increment an Anchor counter using manual discriminator construction
@@ -167,23 +1037,22 @@ fn process_increment_anchor_counter(accounts: &[AccountInfo]) -> ProgramResult {
 /// - Maintenance: Must update manually when the Anchor program changes
 /// - No type safety: Easy to pass wrong accounts or wrong order
 fn process_increment_anchor_counter_raw(accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
     let accounts_iter = &mut accounts.iter();
 
-    let anchor_counter_account = next_account_info(accounts_iter)?;
-    let anchor_authority_account = next_account_info(accounts_iter)?;
-    let anchor_program = next_account_info(accounts_iter)?;
+    let anchor_counter_account = next_account(accounts_iter, "anchor counter")?;
+    let anchor_authority_account = next_account(accounts_iter, "anchor authority")?;
+    let anchor_program = next_account(accounts_iter, "anchor program")?;
 
-    msg!("Performing CPI to Anchor program using manual discriminator...");
+    // Without the Anchor crate there's no ID to compare against, only deployability
+    check_cpi_program(anchor_program, None)?;
+    check_anchor_counter(anchor_counter_account, anchor_program)?;
 
-    // Anchor's increment_counter instruction discriminator (from IDL)
-    // This is derived from: anchor_lang::prelude::hash::hash(b"global:increment_counter")
-    // For Anchor, the discriminator is the first 8 bytes of the SHA256 hash
-    // of the namespace:instruction_name string
-    // You can find this in: anchor-counter/target/idl/anchor_counter.json
-    let discriminator: [u8; 8] = [16, 125, 2, 171, 73, 24, 207, 229];
+    msg!("Performing CPI to Anchor program using manual discriminator...");
 
-    // Build the instruction data (just the discriminator, no additional args)
-    let instruction_data = discriminator.to_vec();
+    // Anchor's increment_counter instruction: the discriminator from the IDL
+    // (first 8 bytes of sha256("global:increment_counter")) and no arguments
+    let instruction_data = AnchorIx::IncrementCounter.to_data();
 
     // Create the instruction for the CPI
     let cpi_instruction = solana_program::instruction::Instruction {
@@ -199,7 +1068,7 @@ fn process_increment_anchor_counter_raw(accounts: &[AccountInfo]) -> ProgramResu
     };
 
     // Invoke the CPI
-    invoke(
+    invoke_logged(
         &cpi_instruction,
         &[
             anchor_counter_account.clone(),
@@ -236,9 +1105,10 @@ fn process_increment_counter_self_cpi(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
     let accounts_iter = &mut accounts.iter();
-    let counter_account = next_account_info(accounts_iter)?;
-    let counter_program = next_account_info(accounts_iter)?;
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
 
     // Verify we're calling our own program
     if counter_program.key != program_id {
@@ -264,32 +1134,27 @@ fn process_increment_counter_self_cpi(
     };
 
     // Invoke the CPI
-    invoke(&cpi_instruction, &[counter_account.clone()])?;
+    invoke_logged(&cpi_instruction, std::slice::from_ref(counter_account))?;
 
     msg!("Successfully incremented counter via self-CPI (Codama-style pattern)");
     Ok(())
 }
 
-/// Perform a self-CPI using the actual Codama-generated CPI client
-/// This demonstrates using Codama's auto-generated CPI helpers directly
-///
-/// The Codama-generated client (in clients/rust/instructions/) provides:
-/// - IncrementCounterCpi - Direct CPI struct
-/// - IncrementCounterCpiBuilder - Builder pattern for constructing CPIs
+/// Initialize a counter, then increment it by self-CPI into `IncrementCounter`
 ///
-/// Benefits of using Codama's generated client:
-/// - Type-safe account structures
-/// - Builder pattern for clarity
-/// - Auto-generated from IDL
-/// - Consistent with Anchor's CPI style
-/// - Handles instruction data serialization automatically
-fn process_increment_counter_codama_client(
+/// The composability use case above: the init logic runs directly, and the
+/// increment is delegated to the program's own instruction rather than duplicated.
+fn process_init_and_increment_self_cpi(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
+    initial_value: u64,
 ) -> ProgramResult {
+    expect_accounts(accounts, 4)?;
     let accounts_iter = &mut accounts.iter();
-    let counter_account = next_account_info(accounts_iter)?;
-    let counter_program = next_account_info(accounts_iter)?;
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
 
     // Verify we're calling our own program
     if counter_program.key != program_id {
@@ -297,9 +1162,1534 @@ fn process_increment_counter_codama_client(
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    codama_client::instructions::IncrementCounterCpiBuilder::new(counter_program)
-        .counter(counter_account)
-        .invoke()?;
+    create_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        initial_value,
+        &[],
+    )?;
 
-    Ok(())
+    let cpi_instruction = solana_program::instruction::Instruction {
+        program_id: *program_id,
+        accounts: vec![solana_program::instruction::AccountMeta::new(
+            *counter_account.key,
+            false,
+        )],
+        data: borsh::to_vec(&CounterInstruction::IncrementCounter)?,
+    };
+    invoke_logged(
+        &cpi_instruction,
+        &[counter_account.clone(), counter_program.clone()],
+    )?;
+
+    msg!("Counter initialized and incremented via self-CPI");
+    Ok(())
+}
+
+/// Create the program-wide stats PDA `["stats"]` with a zero total
+fn process_initialize_stats(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let stats_account = next_account(accounts_iter, "stats")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    if !payer_account.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let (stats_pda, bump) = Pubkey::find_program_address(&[STATS_SEED], program_id);
+    if stats_account.key != &stats_pda {
+        msg!("Error: Stats account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    allocate_counter_account(
+        program_id,
+        stats_account,
+        payer_account,
+        system_program,
+        StatsAccount::LEN,
+        &[&[STATS_SEED, &[bump]]],
+    )?;
+
+    let stats_data = StatsAccount {
+        discriminator: StatsAccount::DISCRIMINATOR,
+        total_increments: 0,
+        bump,
+    };
+    stats_data.serialize(&mut &mut stats_account.data.borrow_mut()[..])?;
+
+    msg!("Stats account initialized: {}", stats_account.key);
+    Ok(())
+}
+
+/// Add one increment to the program-wide total in the stats PDA
+fn record_increment(program_id: &Pubkey, stats_account: &AccountInfo) -> ProgramResult {
+    if stats_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut stats_data = stats_account.data.borrow_mut();
+    let mut stats = StatsAccount::load(&stats_data)?;
+
+    // The stored bump skips the search `find_program_address` would do
+    let stats_pda = Pubkey::create_program_address(&[STATS_SEED, &[stats.bump]], program_id)?;
+    if stats_account.key != &stats_pda {
+        msg!("Error: Stats account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    stats.total_increments = stats
+        .total_increments
+        .checked_add(1)
+        .ok_or(CounterError::CounterOverflow)?;
+    stats.serialize(&mut &mut stats_data[..])?;
+
+    msg!("Total increments: {}", stats.total_increments);
+    Ok(())
+}
+
+/// Perform a self-CPI using the actual Codama-generated CPI client
+/// This demonstrates using Codama's auto-generated CPI helpers directly
+///
+/// The Codama-generated client (in clients/rust/instructions/) provides:
+/// - IncrementCounterCpi - Direct CPI struct
+/// - IncrementCounterCpiBuilder - Builder pattern for constructing CPIs
+///
+/// Benefits of using Codama's generated client:
+/// - Type-safe account structures
+/// - Builder pattern for clarity
+/// - Auto-generated from IDL
+/// - Consistent with Anchor's CPI style
+/// - Handles instruction data serialization automatically
+fn process_increment_counter_codama_client(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
+
+    // Verify we're calling our own program
+    if counter_program.key != program_id {
+        msg!("Error: Program ID mismatch");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Verify account ownership, same as the non-Codama increment path
+    if counter_account.owner != program_id {
+        msg!("Error: Counter account is not owned by this program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    codama_client::instructions::IncrementCounterCpiBuilder::new(counter_program)
+        .counter(counter_account)
+        .invoke()?;
+
+    Ok(())
+}
+
+/// Perform a self-CPI that decrements a counter using the Codama-generated CPI client
+/// The decrement counterpart of `process_increment_counter_codama_client`
+fn process_decrement_counter_codama_client(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
+
+    // Verify we're calling our own program
+    if counter_program.key != program_id {
+        msg!("Error: Program ID mismatch");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Verify account ownership, same as the non-Codama decrement path
+    if counter_account.owner != program_id {
+        msg!("Error: Counter account is not owned by this program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    codama_client::instructions::DecrementCounterCpiBuilder::new(counter_program)
+        .counter(counter_account)
+        .invoke()?;
+
+    Ok(())
+}
+
+/// Perform a self-CPI that increments a PDA counter using the Codama-generated CPI client
+/// This is the signed variant of `process_increment_counter_codama_client`
+///
+/// The counter must be the PDA derived from ["counter", payer]. The CPI is sent with
+/// `invoke_signed`, so the program signs for the PDA just like it would for any
+/// PDA-owned account in a real CPI.
+fn process_increment_pda_counter_codama_client(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
+
+    // Verify we're calling our own program
+    if counter_program.key != program_id {
+        msg!("Error: Program ID mismatch");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Manual PDA derivation and validation
+    let (counter_pda, bump) =
+        Pubkey::find_program_address(&[COUNTER_SEED, payer_account.key.as_ref()], program_id);
+    if counter_account.key != &counter_pda {
+        msg!("Error: Counter account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    codama_client::instructions::IncrementCounterCpiBuilder::new(counter_program)
+        .counter(counter_account)
+        .invoke_signed(&[&[COUNTER_SEED, payer_account.key.as_ref(), &[bump]]])?;
+
+    Ok(())
+}
+
+/// Perform a self-CPI that initializes a counter using the Codama-generated CPI client
+/// This is the initialization counterpart of `process_increment_counter_codama_client`,
+/// so every core instruction is reachable through the generated client.
+///
+/// The generated InitializeCounterCpiBuilder serializes `initial_value` for us and
+/// forwards the counter and payer signatures from the outer transaction.
+fn process_initialize_via_codama(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 4)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+    let counter_program = next_account(accounts_iter, "counter program")?;
+
+    // Verify we're calling our own program
+    if counter_program.key != program_id {
+        msg!("Error: Program ID mismatch");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    codama_client::instructions::InitializeCounterCpiBuilder::new(counter_program)
+        .counter(counter_account)
+        .payer(payer_account)
+        .system_program(system_program)
+        .initial_value(initial_value)
+        .invoke()?;
+
+    Ok(())
+}
+
+/// Increment the counter only when two distinct signers approve
+///
+/// Nothing extra is stored on the counter: the two-signer requirement is
+/// enforced purely at runtime, then the regular increment is applied.
+fn process_increment_counter_multisig(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let first_signer = next_account(accounts_iter, "first signer")?;
+    let second_signer = next_account(accounts_iter, "second signer")?;
+
+    // Both approvers must sign the transaction
+    if !first_signer.is_signer || !second_signer.is_signer {
+        msg!("Error: Both approvers must sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // The same key can't approve twice
+    if first_signer.key == second_signer.key {
+        msg!("Error: Approvers must be distinct");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))
+}
+
+// Increment the counter only if nobody else has moved it since the caller's read
+fn process_compare_and_increment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    expected: u64,
+) -> ProgramResult {
+    process_assert_count(program_id, accounts, expected)?;
+    process_increment_counter(program_id, accounts)
+}
+
+// Fail unless the counter holds `expected`, changing nothing either way
+fn process_assert_count(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    expected: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let current = CounterAccount::try_from_slice_compat(&counter_account.data.borrow())?.count;
+    if current != expected {
+        msg!("Error: Counter is {}, expected {}", current, expected);
+        return Err(CounterError::CasMismatch.into());
+    }
+
+    Ok(())
+}
+
+// Decrement the counter, stopping at zero instead of failing on underflow
+fn process_decrement_counter_saturating(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    ensure_enabled(counter_data.enabled)?;
+
+    // Never underflows: a zero counter stays at zero
+    counter_data.count = counter_data.count.saturating_sub(1);
+
+    counter_data.store(&mut data)?;
+
+    log_count("decrement", "Counter decremented to", counter_data.count);
+    Ok(())
+}
+
+// Increment the counter, stopping at u64::MAX instead of failing on overflow
+fn process_increment_counter_saturating(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    ensure_enabled(counter_data.enabled)?;
+
+    // Never overflows: a counter at its cap (or u64::MAX without one) stays pinned there
+    let ceiling = match counter_data.max_value {
+        0 => u64::MAX,
+        max_value => max_value,
+    };
+    if counter_data.count < ceiling {
+        counter_data.count += 1;
+    }
+
+    counter_data.store(&mut data)?;
+
+    log_count("increment", "Counter incremented to", counter_data.count);
+    Ok(())
+}
+
+// Initialize a new 128-bit counter account
+fn process_initialize_big_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u128,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        BigCounterAccount::LEN,
+        &[],
+    )?;
+
+    let counter_data = BigCounterAccount {
+        discriminator: BigCounterAccount::DISCRIMINATOR,
+        count: initial_value,
+    };
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+    counter_data.serialize(&mut account_data)?;
+
+    msg!("Big counter initialized with value: {}", initial_value);
+
+    Ok(())
+}
+
+// Increment a 128-bit counter by one
+fn process_increment_big_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = BigCounterAccount::load(&data)?;
+
+    counter_data.count = counter_data
+        .count
+        .checked_add(1)
+        .ok_or(CounterError::CounterOverflow)?;
+
+    counter_data.serialize(&mut &mut data[..])?;
+
+    msg!("Big counter incremented to: {}", counter_data.count);
+    Ok(())
+}
+
+// Increment the counter unless it was updated less than `min_interval_secs` ago
+fn process_increment_counter_rate_limited(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_interval_secs: i64,
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Only the current layout stores the timestamp the guard depends on
+    if counter_account.data_len() < CounterAccount::LEN {
+        msg!("Error: Counter must be migrated before using rate-limited increments");
+        return Err(ProgramError::AccountDataTooSmall);
+    }
+
+    let counter_data = CounterAccount::try_from_slice_compat(&counter_account.data.borrow())?;
+
+    // A counter that was never incremented has no timestamp yet
+    let now = Clock::get()?.unix_timestamp;
+    if counter_data.last_updated != 0
+        && now.saturating_sub(counter_data.last_updated) < min_interval_secs
+    {
+        msg!(
+            "Error: Counter updated at {}, next increment allowed at {}",
+            counter_data.last_updated,
+            counter_data.last_updated.saturating_add(min_interval_secs)
+        );
+        return Err(CounterError::RateLimited.into());
+    }
+
+    // The regular increment records the new timestamp
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))
+}
+
+// Decrement the counter by its step, rejecting underflow
+fn process_decrement_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    ensure_enabled(counter_data.enabled)?;
+
+    counter_data.count = counter_data
+        .count
+        .checked_sub(counter_data.increment_step())
+        .ok_or(CounterError::CounterUnderflow)?;
+
+    counter_data.store(&mut data)?;
+
+    log_count("decrement", "Counter decremented to", counter_data.count);
+    Ok(())
+}
+
+// Move the counter up or down by a signed delta
+fn process_adjust_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delta: i64,
+) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+
+    // Adjusting can set any value, including zero, so only the authority may do it
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data =
+        load_with_authority(program_id, counter_account, authority_account, &data)?;
+    ensure_enabled(counter_data.enabled)?;
+
+    counter_data.count = counter_data
+        .count
+        .checked_add_signed(delta)
+        .ok_or_else(|| {
+            msg!(
+                "Error: Adjusting {} by {} leaves the u64 range",
+                counter_data.count,
+                delta
+            );
+            if delta < 0 {
+                CounterError::CounterUnderflow
+            } else {
+                CounterError::CounterOverflow
+            }
+        })?;
+    ensure_within_max(counter_data.count, counter_data.max_value)?;
+
+    counter_data.store(&mut data)?;
+
+    log_count("adjust", "Counter adjusted to", counter_data.count);
+    Ok(())
+}
+
+// Increment once per nonce: each must be strictly above the last one accepted
+fn process_increment_counter_with_nonce(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    nonce: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+
+    if nonce <= counter_data.nonce {
+        msg!(
+            "Error: Nonce {} is not above the last accepted nonce {}",
+            nonce,
+            counter_data.nonce
+        );
+        return Err(CounterError::StaleNonce.into());
+    }
+
+    counter_data.count = next_count(
+        counter_data.enabled,
+        counter_data.count,
+        counter_data.increment_step(),
+        counter_data.max_value,
+        counter_data.overflow_behavior,
+    )?;
+    counter_data.last_updated = Clock::get()?.unix_timestamp;
+    counter_data.nonce = nonce;
+
+    // Legacy counters have no room for the nonce until migrated
+    counter_data.store(&mut data)?;
+
+    set_return_data(&counter_data.count.to_le_bytes());
+    log_count("increment", "Counter incremented to", counter_data.count);
+    Ok(())
+}
+
+// Write the source counter's count into the destination counter
+fn process_copy_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let source_account = next_account(accounts_iter, "source")?;
+    let destination_account = next_account(accounts_iter, "destination")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+
+    // Verify account ownership (the destination is checked with its authority below)
+    if source_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // Read first and release the borrow, so copying a counter onto itself works
+    let count = CounterAccount::try_from_slice_compat(&source_account.data.borrow())?.count;
+
+    // Copying can set any value, including zero, so only the destination's authority may do it
+    let mut data = destination_account.data.borrow_mut();
+    let mut counter_data =
+        load_with_authority(program_id, destination_account, authority_account, &data)?;
+    ensure_enabled(counter_data.enabled)?;
+    ensure_within_max(count, counter_data.max_value)?;
+    counter_data.count = count;
+    counter_data.store(&mut data)?;
+
+    log_count("copy", "Counter copied to", count);
+    Ok(())
+}
+
+/// Increment the counter, then CPI into SPL Token to transfer a single token
+/// This demonstrates calling an external, widely-used program through its own crate
+///
+/// Unlike the Anchor and Codama examples, the SPL Token program ships a hand-written
+/// instruction builder (`spl_token::instruction::transfer`) rather than an IDL-generated one.
+fn process_increment_and_transfer(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 5)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let source_account = next_account(accounts_iter, "source")?;
+    let destination_account = next_account(accounts_iter, "destination")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let token_program = next_account(accounts_iter, "token program")?;
+
+    // Verify we're calling the real SPL Token program
+    if token_program.key != &spl_token::ID {
+        msg!("Error: Invalid token program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))?;
+
+    msg!("Performing CPI to SPL Token transfer...");
+    invoke(
+        &spl_token::instruction::transfer(
+            token_program.key,
+            source_account.key,
+            destination_account.key,
+            authority_account.key,
+            &[],
+            1,
+        )?,
+        &[
+            source_account.clone(),
+            destination_account.clone(),
+            authority_account.clone(),
+            token_program.clone(),
+        ],
+    )?;
+
+    msg!("Transferred 1 token to {}", destination_account.key);
+    Ok(())
+}
+
+/// SPL Memo program (v3), which logs its UTF-8 instruction data
+const MEMO_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+// Increment the counter and CPI the message into the SPL Memo program
+fn process_log_memo(program_id: &Pubkey, accounts: &[AccountInfo], message: &str) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let memo_program = next_account(accounts_iter, "memo program")?;
+
+    // Verify we're calling the real Memo program
+    if memo_program.key != &MEMO_PROGRAM_ID {
+        msg!("Error: Invalid memo program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))?;
+
+    // The memo is the raw instruction data; no signers are required
+    msg!("Performing CPI to SPL Memo...");
+    invoke(
+        &solana_program::instruction::Instruction {
+            program_id: MEMO_PROGRAM_ID,
+            accounts: vec![],
+            data: message.as_bytes().to_vec(),
+        },
+        std::slice::from_ref(memo_program),
+    )?;
+
+    Ok(())
+}
+
+// Initialize a new counter with a history ring buffer
+fn process_initialize_history_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        HistoryCounterAccount::LEN,
+        &[],
+    )?;
+
+    let mut counter_data = HistoryCounterAccount {
+        discriminator: HistoryCounterAccount::DISCRIMINATOR,
+        count: initial_value,
+        history: [0; HISTORY_LEN],
+        history_pos: 0,
+    };
+    counter_data.push(initial_value);
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+    counter_data.serialize(&mut account_data)?;
+
+    msg!("History counter initialized with value: {}", initial_value);
+
+    Ok(())
+}
+
+// Initialize a new counter whose size depends on its name
+fn process_initialize_named_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+    name: String,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    if name.len() > NamedCounterAccount::MAX_NAME_LEN {
+        msg!(
+            "Error: Name is {} bytes, at most {} allowed",
+            name.len(),
+            NamedCounterAccount::MAX_NAME_LEN
+        );
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Size the account to exactly this name
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        NamedCounterAccount::space(name.len()),
+        &[],
+    )?;
+
+    let counter_data = NamedCounterAccount {
+        discriminator: NamedCounterAccount::DISCRIMINATOR,
+        count: initial_value,
+        name,
+    };
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+    counter_data.serialize(&mut account_data)?;
+
+    msg!(
+        "Named counter {:?} initialized with value: {}",
+        counter_data.name,
+        initial_value
+    );
+
+    Ok(())
+}
+
+// Increment a history counter and remember the new value
+fn process_increment_history_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = HistoryCounterAccount::load(&data)?;
+
+    counter_data.count = counter_data
+        .count
+        .checked_add(1)
+        .ok_or(CounterError::CounterOverflow)?;
+    counter_data.push(counter_data.count);
+
+    counter_data.serialize(&mut &mut data[..])?;
+
+    msg!("History counter incremented to: {}", counter_data.count);
+    Ok(())
+}
+
+// Initialize a counter that only leaves of the `merkle_root` tree may increment
+fn process_initialize_whitelist_counter(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    initial_value: u64,
+    merkle_root: [u8; 32],
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let payer_account = next_account(accounts_iter, "payer")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    allocate_counter_account(
+        program_id,
+        counter_account,
+        payer_account,
+        system_program,
+        WhitelistCounterAccount::LEN,
+        &[],
+    )?;
+
+    let counter_data = WhitelistCounterAccount {
+        discriminator: WhitelistCounterAccount::DISCRIMINATOR,
+        count: initial_value,
+        merkle_root,
+    };
+    let mut account_data = &mut counter_account.data.borrow_mut()[..];
+    counter_data.serialize(&mut account_data)?;
+
+    msg!(
+        "Whitelist counter initialized with value: {}",
+        initial_value
+    );
+
+    Ok(())
+}
+
+// Increment a whitelist counter by one once `proof` ties `leaf` to its root
+fn process_increment_if_whitelisted(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proof: &[[u8; 32]],
+) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let member_account = next_account(accounts_iter, "member")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // The leaf comes from the signer, so a proof only works for its own wallet
+    if !member_account.is_signer {
+        msg!("Error: Whitelisted member must sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let leaf = merkle::hash_leaf(member_account.key);
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = WhitelistCounterAccount::load(&data)?;
+
+    if !merkle::verify_proof(&counter_data.merkle_root, &leaf, proof) {
+        msg!("Error: {} is not in the whitelist", member_account.key);
+        return Err(CounterError::InvalidMerkleProof.into());
+    }
+
+    counter_data.count = counter_data
+        .count
+        .checked_add(1)
+        .ok_or(CounterError::CounterOverflow)?;
+    counter_data.serialize(&mut &mut data[..])?;
+
+    msg!("Whitelist counter incremented to: {}", counter_data.count);
+    Ok(())
+}
+
+// Reset the counter to zero, gated by the stored authority
+fn process_reset_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data =
+        load_with_authority(program_id, counter_account, authority_account, &data)?;
+    ensure_enabled(counter_data.enabled)?;
+
+    counter_data.count = 0;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter reset by: {}", authority_account.key);
+    Ok(())
+}
+
+// Hand the counter's authority to a new key
+fn process_transfer_authority(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    new_authority: Pubkey,
+) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data =
+        load_with_authority(program_id, counter_account, authority_account, &data)?;
+
+    counter_data.authority = new_authority;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter authority transferred to: {}", new_authority);
+    Ok(())
+}
+
+// Close the counter, moving its lamports to the recipient
+//
+// The program owns the account, so it can debit it directly: PDA counters close
+// the same way as keypair ones, with only the stored authority signing.
+fn process_close_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let recipient_account = next_account(accounts_iter, "recipient")?;
+
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    let reclaimed = close_program_account(counter_account, recipient_account)?;
+
+    msg!(
+        "Counter closed, {} lamports sent to: {}",
+        reclaimed,
+        recipient_account.key
+    );
+    Ok(())
+}
+
+/// Empty a program-owned account into `recipient` and hand it back to the System Program
+///
+/// Returns the lamports moved.
+fn close_program_account(
+    account: &AccountInfo,
+    recipient: &AccountInfo,
+) -> Result<u64, ProgramError> {
+    if account.key == recipient.key {
+        msg!("Error: Recipient cannot be the account being closed");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let reclaimed = account.lamports();
+    **recipient.try_borrow_mut_lamports()? = recipient
+        .lamports()
+        .checked_add(reclaimed)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.resize(0)?;
+    account.assign(&solana_program::system_program::ID);
+    Ok(reclaimed)
+}
+
+// Record a commitment to a secret nonce, revealed later by `RevealIncrement`
+fn process_commit_increment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    hash: [u8; 32],
+) -> ProgramResult {
+    expect_accounts(accounts, 4)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let commitment_account = next_account(accounts_iter, "commitment")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    let (commitment_pda, bump) =
+        Pubkey::find_program_address(&[COMMITMENT_SEED, counter_account.key.as_ref()], program_id);
+    if commitment_account.key != &commitment_pda {
+        msg!("Error: Commitment account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // Fails with AlreadyInitialized while an earlier commitment is unrevealed
+    allocate_counter_account(
+        program_id,
+        commitment_account,
+        authority_account,
+        system_program,
+        CommitmentAccount::LEN,
+        &[&[COMMITMENT_SEED, counter_account.key.as_ref(), &[bump]]],
+    )?;
+
+    let commitment_data = CommitmentAccount {
+        discriminator: CommitmentAccount::DISCRIMINATOR,
+        hash,
+        bump,
+    };
+    commitment_data.serialize(&mut &mut commitment_account.data.borrow_mut()[..])?;
+
+    msg!("Increment committed for counter: {}", counter_account.key);
+    Ok(())
+}
+
+// Increment once `nonce` matches the pending commitment, then close the commitment
+fn process_reveal_increment(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    nonce: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let commitment_account = next_account(accounts_iter, "commitment")?;
+
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    let (commitment_pda, _bump) =
+        Pubkey::find_program_address(&[COMMITMENT_SEED, counter_account.key.as_ref()], program_id);
+    if commitment_account.key != &commitment_pda {
+        msg!("Error: Commitment account is not the expected PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+    if commitment_account.owner != program_id || commitment_account.data_is_empty() {
+        msg!("Error: No pending commitment for this counter");
+        return Err(CounterError::NoCommitment.into());
+    }
+
+    let commitment_data = CommitmentAccount::load(&commitment_account.data.borrow())?;
+    if CommitmentAccount::hash_nonce(nonce) != commitment_data.hash {
+        msg!("Error: Nonce does not match the commitment");
+        return Err(CounterError::RevealMismatch.into());
+    }
+
+    // The rent for the commitment goes back to the authority that paid it
+    close_program_account(commitment_account, authority_account)?;
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))
+}
+
+// Withdraw whatever the counter holds beyond its rent-exempt minimum
+fn process_withdraw_surplus(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let destination_account = next_account(accounts_iter, "destination")?;
+
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    let minimum_balance = Rent::get()?.minimum_balance(counter_account.data_len());
+    let surplus = counter_account.lamports().saturating_sub(minimum_balance);
+
+    // The program owns the counter, so it can debit it without a System Program CPI
+    **counter_account.try_borrow_mut_lamports()? -= surplus;
+    **destination_account.try_borrow_mut_lamports()? = destination_account
+        .lamports()
+        .checked_add(surplus)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    msg!(
+        "Withdrew {} surplus lamports to: {}",
+        surplus,
+        destination_account.key
+    );
+    Ok(())
+}
+
+// Expose the funding a new counter needs under the cluster's current rent
+fn process_query_rent(accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 0)?;
+
+    let minimum_balance = Rent::get()?.minimum_balance(CounterAccount::LEN);
+    set_return_data(&minimum_balance.to_le_bytes());
+
+    msg!(
+        "Rent-exempt minimum for {} bytes: {}",
+        CounterAccount::LEN,
+        minimum_balance
+    );
+    Ok(())
+}
+
+/// Load a counter after checking ownership and that its stored authority signed
+///
+/// The native equivalent of Anchor's `has_one = authority` plus `Signer<'info>`.
+fn load_with_authority(
+    program_id: &Pubkey,
+    counter_account: &AccountInfo,
+    authority_account: &AccountInfo,
+    data: &[u8],
+) -> Result<CounterAccount, ProgramError> {
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !authority_account.is_signer {
+        msg!("Error: Authority must sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let counter_data = CounterAccount::try_from_slice_compat(data)?;
+    if counter_data.authority != *authority_account.key {
+        msg!(
+            "Error: {} is not the counter authority",
+            authority_account.key
+        );
+        return Err(CounterError::InvalidAuthority.into());
+    }
+
+    Ok(counter_data)
+}
+
+// Expose the current count through return data without touching the account
+fn process_get_count(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 1)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let counter_data = CounterAccount::try_from_slice_compat(&counter_account.data.borrow())?;
+
+    // CPI callers read this with `get_return_data` right after the invoke
+    set_return_data(&counter_data.count.to_le_bytes());
+
+    msg!("Counter value: {}", counter_data.count);
+    Ok(())
+}
+
+// Set the persistent step used by IncrementCounter (authority only)
+fn process_set_step(program_id: &Pubkey, accounts: &[AccountInfo], step: u64) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // A huge step forces overflow, so only the authority may change it
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the step yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.step = step;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter step set to: {}", step);
+    Ok(())
+}
+
+// Flip the enabled flag checked by IncrementCounter (authority only)
+fn process_toggle_enabled(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Only the authority may pause or unpause, checked before anything is resized
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the flag yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.enabled = !counter_data.enabled;
+    counter_data.store(&mut data)?;
+
+    msg!(
+        "Counter enabled: {} (by {})",
+        counter_data.enabled,
+        authority_account.key
+    );
+    Ok(())
+}
+
+// Set or clear the delegate allowed to increment alongside the authority
+fn process_set_delegate(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    delegate: Option<Pubkey>,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the delegate yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.delegate = delegate;
+    counter_data.store(&mut data)?;
+
+    match delegate {
+        Some(delegate) => msg!("Counter delegate set to: {}", delegate),
+        None => msg!("Counter delegate cleared"),
+    }
+    Ok(())
+}
+
+// Increment on behalf of the authority, as the authority itself or its delegate
+fn process_increment_as_delegate(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let signer_account = next_account(accounts_iter, "signer")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !signer_account.is_signer {
+        msg!("Error: Authority or delegate must sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let counter_data = CounterAccount::try_from_slice_compat(&counter_account.data.borrow())?;
+    if !counter_data.can_increment(signer_account.key) {
+        msg!(
+            "Error: {} is neither the counter authority nor its delegate",
+            signer_account.key
+        );
+        return Err(CounterError::InvalidAuthority.into());
+    }
+
+    process_increment_counter(program_id, std::slice::from_ref(counter_account))
+}
+
+// Save the current count as a checkpoint
+fn process_snapshot_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // A snapshot is a rollback point, so only the authority may take one
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the snapshot yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.snapshot = counter_data.count;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter snapshot taken at: {}", counter_data.snapshot);
+    Ok(())
+}
+
+// Append an entry to the counter's log, growing the account to fit it
+fn process_append_log(program_id: &Pubkey, accounts: &[AccountInfo], entry: u64) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // The log lives in the counter's account, so only its authority may grow it
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // The log starts after the current layout, so older counters grow to it first
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    // The stored entry count, not the account size, says where the log ends
+    let entries = CounterAccount::log_len(&counter_account.data.borrow());
+    let entry_start = CounterAccount::LEN
+        + CounterAccount::LOG_COUNT_LEN
+        + entries * CounterAccount::LOG_ENTRY_LEN;
+    let entry_end = entry_start + CounterAccount::LOG_ENTRY_LEN;
+
+    // Pre-sized counters may already have room for the entry
+    if counter_account.data_len() < entry_end {
+        grow_account(
+            counter_account,
+            authority_account,
+            system_program,
+            entry_end,
+        )?;
+    }
+
+    let mut data = counter_account.data.borrow_mut();
+    data[entry_start..entry_end].copy_from_slice(&entry.to_le_bytes());
+    data[CounterAccount::LEN..CounterAccount::LEN + CounterAccount::LOG_COUNT_LEN]
+        .copy_from_slice(&(entries as u32 + 1).to_le_bytes());
+
+    msg!("Log entry {} appended ({} entries)", entry, entries + 1);
+    Ok(())
+}
+
+// Stamp the counter with the epoch it was last touched in
+fn process_record_epoch(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Stamping may resize the counter, so only its authority may do it
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the epoch yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.last_epoch = Clock::get()?.epoch;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter epoch recorded: {}", counter_data.last_epoch);
+    Ok(())
+}
+
+// Roll the count back to the last checkpoint
+fn process_restore_counter(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 2)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data =
+        load_with_authority(program_id, counter_account, authority_account, &data)?;
+
+    // The snapshot may predate a pause or a lower cap
+    ensure_enabled(counter_data.enabled)?;
+    ensure_within_max(counter_data.snapshot, counter_data.max_value)?;
+    counter_data.count = counter_data.snapshot;
+    counter_data.store(&mut data)?;
+
+    log_count("restore", "Counter restored to", counter_data.count);
+    Ok(())
+}
+
+// Set the cap enforced by IncrementCounter (authority only)
+fn process_set_max_value(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    max_value: u64,
+) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // A cap at the current count freezes the counter, so only its authority may set one
+    load_with_authority(
+        program_id,
+        counter_account,
+        authority_account,
+        &counter_account.data.borrow(),
+    )?;
+
+    // Older counters don't have room for the cap yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.max_value = max_value;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter max value set to: {}", max_value);
+    Ok(())
+}
+
+// Set the first authority of a counter created before authorities existed
+//
+// Nothing on-chain records who created such a counter, so the claimant proves it
+// the way the original `InitializeCounter` did: the counter's keypair signs, or the
+// counter is the claimant's `["counter", claimant]` PDA.
+fn process_claim_authority(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    expect_accounts(accounts, 3)?;
+    let accounts_iter = &mut accounts.iter();
+    let counter_account = next_account(accounts_iter, "counter")?;
+    let authority_account = next_account(accounts_iter, "authority")?;
+    let system_program = next_account(accounts_iter, "system program")?;
+
+    // Verify account ownership
+    if counter_account.owner != program_id {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if !authority_account.is_signer {
+        msg!("Error: Authority must sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Claiming is one-time: a set authority can only be changed by TransferAuthority
+    let counter_data = CounterAccount::load(&counter_account.data.borrow())?;
+    if counter_data.authority != Pubkey::default() {
+        msg!("Error: Counter already has an authority");
+        return Err(CounterError::InvalidAuthority.into());
+    }
+
+    let (counter_pda, _bump) =
+        Pubkey::find_program_address(&[COUNTER_SEED, authority_account.key.as_ref()], program_id);
+    if !counter_account.is_signer && counter_account.key != &counter_pda {
+        msg!("Error: Counter keypair must sign, or be the authority's counter PDA");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    // Older counters don't have room for the authority yet
+    migrate_counter_account(counter_account, authority_account, system_program)?;
+
+    let mut data = counter_account.data.borrow_mut();
+    let mut counter_data = CounterAccount::try_from_slice_compat(&data)?;
+    counter_data.authority = *authority_account.key;
+    counter_data.store(&mut data)?;
+
+    msg!("Counter authority claimed by: {}", authority_account.key);
+    Ok(())
+}
+
+/// Grow a counter created with an older layout to `CounterAccount::LEN`
+///
+/// The payer tops up the lamports needed to keep the larger account rent exempt.
+/// Only the layout changes: the payer is not made the authority, so a counter
+/// that predates authorities still needs `ClaimAuthority` before it can be
+/// configured or closed.
+/// Accounts that already use the current layout are left untouched, and anything
+/// that isn't a counter fails with `InvalidAccountData` before it is resized.
+fn migrate_counter_account<'a>(
+    counter_account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> ProgramResult {
+    let old_len = counter_account.data_len();
+    if old_len >= CounterAccount::LEN {
+        return Ok(());
+    }
+    CounterAccount::load(&counter_account.data.borrow())?;
+
+    grow_account(
+        counter_account,
+        payer_account,
+        system_program,
+        CounterAccount::LEN,
+    )?;
+
+    msg!(
+        "Counter account migrated from {} to {} bytes",
+        old_len,
+        CounterAccount::LEN
+    );
+    Ok(())
+}
+
+/// Resize `account` to `new_len`, with the payer topping up rent for the new size
+fn grow_account<'a>(
+    account: &AccountInfo<'a>,
+    payer_account: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    new_len: usize,
+) -> ProgramResult {
+    let rent = Rent::get()?;
+    let required_lamports = rent.minimum_balance(new_len);
+    let top_up = required_lamports.saturating_sub(account.lamports());
+
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer_account.key, account.key, top_up),
+            &[
+                payer_account.clone(),
+                account.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
+
+    account.resize(new_len)
 }
@@ -1,7 +1,697 @@
+use crate::errors::CounterError;
 use borsh::{BorshDeserialize, BorshSerialize};
+use bytemuck::{Pod, Zeroable};
 use codama::CodamaAccount;
+use solana_program::{
+    entrypoint::ProgramResult,
+    program_error::ProgramError,
+    program_pack::{Pack, Sealed},
+    pubkey::Pubkey,
+};
 
-#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+/// Seed prefix for PDA counters: `["counter", payer]`
+pub const COUNTER_SEED: &[u8] = b"counter";
+
+/// Seed prefix for a counter's pending commitment: `["commitment", counter]`
+pub const COMMITMENT_SEED: &[u8] = b"commitment";
+
+/// Seed of the program-wide statistics account: `["stats"]`
+pub const STATS_SEED: &[u8] = b"stats";
+
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug, PartialEq, Eq, Clone)]
 pub struct CounterAccount {
     pub count: u64,
+    /// Amount added by `IncrementCounter` (0 means the original step of 1)
+    pub step: u64,
+    /// Unix timestamp of the last increment (0 until the first increment after migrating)
+    pub last_updated: i64,
+    /// Highest value `IncrementCounter` may reach (0 means no cap)
+    pub max_value: u64,
+    /// Key allowed to reset the counter and hand over authority (the initializing payer)
+    pub authority: Pubkey,
+    /// Whether `IncrementCounter` is allowed (a paused counter rejects increments)
+    pub enabled: bool,
+    /// Count saved by `SnapshotCounter`, written back by `RestoreCounter`
+    pub snapshot: u64,
+    /// Key that may also increment via `IncrementAsDelegate`, SPL Token style
+    #[borsh(
+        serialize_with = "fixed_option_pubkey::serialize",
+        deserialize_with = "fixed_option_pubkey::deserialize"
+    )]
+    pub delegate: Option<Pubkey>,
+    /// Epoch stored by the last `RecordEpoch` (0 until then)
+    pub last_epoch: u64,
+    /// What `IncrementCounter` does past `u64::MAX`, as an `OverflowBehavior` (0 errors)
+    pub overflow_behavior: u8,
+    /// Last nonce accepted by `IncrementCounterWithNonce` (0 until then)
+    pub nonce: u64,
+}
+
+/// What `IncrementCounter` does when a count would pass `u64::MAX`
+///
+/// Stored per counter as a `u8`, so one instruction covers what the separate
+/// saturating variants do.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowBehavior {
+    /// Fail with `CounterOverflow`
+    Error = 0,
+    /// Wrap around past zero
+    Wrap = 1,
+    /// Stop at `u64::MAX`
+    Saturate = 2,
+}
+
+impl TryFrom<u8> for OverflowBehavior {
+    type Error = ProgramError;
+
+    fn try_from(value: u8) -> Result<Self, ProgramError> {
+        match value {
+            0 => Ok(OverflowBehavior::Error),
+            1 => Ok(OverflowBehavior::Wrap),
+            2 => Ok(OverflowBehavior::Saturate),
+            _ => Err(ProgramError::InvalidArgument),
+        }
+    }
+}
+
+/// Borsh for `Option<Pubkey>` at a fixed size: a tag byte, then the key or 32 zeros
+///
+/// Plain borsh drops the key bytes for `None`, which would move every field after
+/// the delegate. The IDL describes it as a `(bool, Pubkey)` tuple, which Codama's
+/// renderers decode correctly; they ignore the `fixed` flag on option types.
+mod fixed_option_pubkey {
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use solana_program::pubkey::Pubkey;
+    use std::io::{Error, ErrorKind, Read, Result, Write};
+
+    pub fn serialize<W: Write>(value: &Option<Pubkey>, writer: &mut W) -> Result<()> {
+        match value {
+            Some(key) => {
+                1u8.serialize(writer)?;
+                key.serialize(writer)
+            }
+            None => {
+                0u8.serialize(writer)?;
+                Pubkey::default().serialize(writer)
+            }
+        }
+    }
+
+    pub fn deserialize<R: Read>(reader: &mut R) -> Result<Option<Pubkey>> {
+        let tag = u8::deserialize_reader(reader)?;
+        let key = Pubkey::deserialize_reader(reader)?;
+        match tag {
+            0 => Ok(None),
+            1 => Ok(Some(key)),
+            _ => Err(Error::new(ErrorKind::InvalidData, "Invalid delegate tag")),
+        }
+    }
+}
+
+/// Counters start out enabled, so legacy accounts padded with defaults stay usable
+impl Default for CounterAccount {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            step: 0,
+            last_updated: 0,
+            max_value: 0,
+            authority: Pubkey::default(),
+            enabled: true,
+            snapshot: 0,
+            delegate: None,
+            last_epoch: 0,
+            overflow_behavior: OverflowBehavior::Error as u8,
+            nonce: 0,
+        }
+    }
+}
+
+/// The primary value of a counter, dropping every other field
+impl From<CounterAccount> for u64 {
+    fn from(counter: CounterAccount) -> Self {
+        counter.count
+    }
+}
+
+/// A default counter holding `count`
+impl From<u64> for CounterAccount {
+    fn from(count: u64) -> Self {
+        Self {
+            count,
+            ..Self::default()
+        }
+    }
+}
+
+impl CounterAccount {
+    /// Serialized size of the current layout
+    pub const LEN: usize = 123;
+
+    /// Size of counters created before `step` existed (just `count`)
+    pub const LEGACY_LEN: usize = 8;
+
+    /// Sizes of the layouts released before `LEN`, each a prefix of the current one
+    pub const RELEASED_LENS: [usize; 10] = [8, 16, 24, 32, 64, 65, 73, 106, 114, 115];
+
+    /// Smallest account with room for `last_updated`
+    pub const TIMESTAMP_LEN: usize = 24;
+
+    /// Bytes `AppendLog` adds to the account per entry
+    pub const LOG_ENTRY_LEN: usize = 8;
+
+    /// Bytes of the entry count that `AppendLog` keeps in front of the entries
+    pub const LOG_COUNT_LEN: usize = 4;
+
+    /// Largest account `InitializeCounterWithSize` will allocate (10 KiB)
+    pub const MAX_SPACE: usize = 10 * 1024;
+
+    /// Counter holding `count`, with every other field at its default
+    pub fn new(count: u64) -> Self {
+        Self {
+            count,
+            ..Self::default()
+        }
+    }
+
+    /// Start building a counter whose fields are validated together
+    pub fn builder() -> CounterAccountBuilder {
+        CounterAccountBuilder::default()
+    }
+
+    /// Amount a single increment adds, treating an unset step as 1
+    pub fn increment_step(&self) -> u64 {
+        if self.step == 0 {
+            1
+        } else {
+            self.step
+        }
+    }
+
+    /// Whether `value` is within the configured cap, treating an unset cap as unbounded
+    pub fn within_max(&self, value: u64) -> bool {
+        self.max_value == 0 || value <= self.max_value
+    }
+
+    /// Read a counter from account data, picking the layout from its length
+    ///
+    /// - `LEGACY_LEN` (8 bytes): just `count`, every other field at its default
+    /// - `LEN` or more: the full current layout (trailing bytes, e.g. from
+    ///   `AppendLog`, are not part of the counter)
+    /// - one of `RELEASED_LENS`: a layout from before the later fields existed,
+    ///   which take their default values
+    ///
+    /// Counters carry no discriminator, so anything else, and any data starting
+    /// with the discriminator of another account type, is `InvalidAccountData`.
+    pub fn try_from_slice_compat(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN
+            && (!Self::RELEASED_LENS.contains(&data.len()) || is_discriminated(data))
+        {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        match data.len() {
+            Self::LEGACY_LEN => Ok(Self {
+                count: u64::from_le_bytes(data.try_into().unwrap()),
+                ..Self::default()
+            }),
+            len if len >= Self::LEN => Ok(Self::try_from_slice(&data[..Self::LEN])?),
+            len => {
+                let mut buffer = borsh::to_vec(&Self::default())?;
+                buffer[..len].copy_from_slice(data);
+                Ok(Self::try_from_slice(&buffer)?)
+            }
+        }
+    }
+
+    /// Read a counter from account data, accepting older, shorter layouts
+    ///
+    /// Same as [`CounterAccount::try_from_slice_compat`].
+    pub fn load(data: &[u8]) -> Result<Self, ProgramError> {
+        Self::try_from_slice_compat(data)
+    }
+
+    /// Write a counter back into account data, which may use a legacy layout
+    ///
+    /// A legacy account can only be written while the fields it lacks still hold
+    /// their defaults; otherwise it must be grown to `LEN` first.
+    pub fn store(&self, data: &mut [u8]) -> ProgramResult {
+        let buffer = borsh::to_vec(self)?;
+        if data.len() >= Self::LEN {
+            data[..Self::LEN].copy_from_slice(&buffer);
+            return Ok(());
+        }
+
+        let defaults = borsh::to_vec(&Self::default())?;
+        if buffer[data.len()..] != defaults[data.len()..] {
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+
+        let len = data.len();
+        data.copy_from_slice(&buffer[..len]);
+        Ok(())
+    }
+
+    /// Whether `key` may increment through `IncrementAsDelegate`
+    pub fn can_increment(&self, key: &Pubkey) -> bool {
+        self.authority == *key || self.delegate.as_ref() == Some(key)
+    }
+
+    /// Number of entries written by `AppendLog`: the little-endian u32 after the counter fields
+    pub fn log_len(data: &[u8]) -> usize {
+        data.get(Self::LEN..Self::LEN + Self::LOG_COUNT_LEN)
+            .map_or(0, |count| {
+                u32::from_le_bytes(count.try_into().unwrap()) as usize
+            })
+    }
+
+    /// Entries written by `AppendLog`: `log_len` little-endian u64s after the entry count
+    ///
+    /// Spare bytes past them, such as space reserved by `InitializeCounterWithSize`,
+    /// are not part of the log.
+    pub fn log_entries(data: &[u8]) -> Vec<u64> {
+        data.get(Self::LEN + Self::LOG_COUNT_LEN..)
+            .unwrap_or_default()
+            .chunks_exact(Self::LOG_ENTRY_LEN)
+            .take(Self::log_len(data))
+            .map(|entry| u64::from_le_bytes(entry.try_into().unwrap()))
+            .collect()
+    }
+}
+
+/// Builds a `CounterAccount`, rejecting combinations it could never reach on-chain
+#[derive(Debug, Default)]
+pub struct CounterAccountBuilder {
+    account: CounterAccount,
+}
+
+impl CounterAccountBuilder {
+    pub fn count(mut self, count: u64) -> Self {
+        self.account.count = count;
+        self
+    }
+
+    pub fn step(mut self, step: u64) -> Self {
+        self.account.step = step;
+        self
+    }
+
+    pub fn max_value(mut self, max_value: u64) -> Self {
+        self.account.max_value = max_value;
+        self
+    }
+
+    pub fn authority(mut self, authority: Pubkey) -> Self {
+        self.account.authority = authority;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.account.enabled = enabled;
+        self
+    }
+
+    pub fn overflow_behavior(mut self, overflow_behavior: OverflowBehavior) -> Self {
+        self.account.overflow_behavior = overflow_behavior as u8;
+        self
+    }
+
+    /// Check the invariants and return the counter
+    ///
+    /// A count above a set `max_value` is the state `IncrementCounter` refuses to
+    /// produce, so it is rejected the same way, with `CounterOverflow`.
+    pub fn build(self) -> Result<CounterAccount, CounterError> {
+        if !self.account.within_max(self.account.count) {
+            return Err(CounterError::CounterOverflow);
+        }
+        Ok(self.account)
+    }
+}
+
+/// Account types that start with an 8-byte discriminator
+///
+/// `CounterAccount` predates discriminators and is told apart by its length; every
+/// type added after it is tagged, so none of them can pass for a counter (or for
+/// each other) just because the sizes happen to match.
+pub trait Discriminator: BorshDeserialize {
+    /// First 8 bytes of `sha256("account:<TypeName>")`, as Anchor derives them
+    const DISCRIMINATOR: [u8; 8];
+
+    /// Decode the account, rejecting data that starts with another discriminator
+    fn load(data: &[u8]) -> Result<Self, ProgramError> {
+        if !data.starts_with(&Self::DISCRIMINATOR) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self::try_from_slice(data)?)
+    }
+}
+
+/// Whether `data` starts with the discriminator of one of the tagged account types
+fn is_discriminated(data: &[u8]) -> bool {
+    [
+        BigCounterAccount::DISCRIMINATOR,
+        IndexedCounterAccount::DISCRIMINATOR,
+        HistoryCounterAccount::DISCRIMINATOR,
+        WhitelistCounterAccount::DISCRIMINATOR,
+        CommitmentAccount::DISCRIMINATOR,
+        StatsAccount::DISCRIMINATOR,
+        NamedCounterAccount::DISCRIMINATOR,
+    ]
+    .iter()
+    .any(|discriminator| data.starts_with(discriminator))
+}
+
+/// Counter with a 128-bit value for very high-frequency counting
+///
+/// Separate from `CounterAccount` so the u64 layout (and its clients) stay untouched.
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct BigCounterAccount {
+    /// Always `BigCounterAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    pub count: u128,
+}
+
+impl Discriminator for BigCounterAccount {
+    const DISCRIMINATOR: [u8; 8] = [174, 137, 252, 239, 228, 25, 28, 52];
+}
+
+impl BigCounterAccount {
+    /// Serialized size: discriminator and a single little-endian u128
+    pub const LEN: usize = 8 + 16;
+}
+
+/// One of many counters a payer can own, at the PDA `["counter", payer, index]`
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct IndexedCounterAccount {
+    /// Always `IndexedCounterAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    pub count: u64,
+    /// Index used in the PDA seeds (little-endian u64)
+    pub index: u64,
+    /// Canonical bump of the PDA, so it never has to be searched for again
+    pub bump: u8,
+}
+
+impl Discriminator for IndexedCounterAccount {
+    const DISCRIMINATOR: [u8; 8] = [183, 32, 44, 93, 185, 93, 63, 37];
+}
+
+impl IndexedCounterAccount {
+    /// Serialized size: discriminator, count, index and bump
+    pub const LEN: usize = 8 + 8 + 8 + 1;
+}
+
+/// Number of past values kept by `HistoryCounterAccount`
+pub const HISTORY_LEN: usize = 8;
+
+/// Counter that remembers its last `HISTORY_LEN` values in a ring buffer
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct HistoryCounterAccount {
+    /// Always `HistoryCounterAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    pub count: u64,
+    pub history: [u64; HISTORY_LEN],
+    /// Slot in `history` that the next value is written to
+    pub history_pos: u8,
+}
+
+impl Discriminator for HistoryCounterAccount {
+    const DISCRIMINATOR: [u8; 8] = [75, 143, 98, 142, 100, 98, 213, 218];
+}
+
+impl HistoryCounterAccount {
+    /// Serialized size: discriminator, count, the ring, and its write position
+    pub const LEN: usize = 8 + 8 + 8 * HISTORY_LEN + 1;
+
+    /// Record `value` in the ring, overwriting the oldest entry
+    pub fn push(&mut self, value: u64) {
+        let pos = self.history_pos as usize % HISTORY_LEN;
+        self.history[pos] = value;
+        self.history_pos = ((pos + 1) % HISTORY_LEN) as u8;
+    }
+
+    /// The ring from oldest to newest entry
+    pub fn history_in_order(&self) -> [u64; HISTORY_LEN] {
+        let pos = self.history_pos as usize % HISTORY_LEN;
+        std::array::from_fn(|i| self.history[(pos + i) % HISTORY_LEN])
+    }
+}
+
+/// Counter that only whitelisted leaves may increment, checked against a Merkle root
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct WhitelistCounterAccount {
+    /// Always `WhitelistCounterAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    pub count: u64,
+    /// Root of the keccak Merkle tree of allowed leaves (see `crate::merkle`)
+    pub merkle_root: [u8; 32],
+}
+
+impl Discriminator for WhitelistCounterAccount {
+    const DISCRIMINATOR: [u8; 8] = [117, 106, 232, 41, 120, 132, 59, 4];
+}
+
+impl WhitelistCounterAccount {
+    /// Serialized size: discriminator, count and the root
+    pub const LEN: usize = 8 + 8 + 32;
+}
+
+/// Pending `CommitIncrement` for a counter, at the PDA `["commitment", counter]`
+///
+/// Lives beside the counter rather than in it, so any counter can commit without
+/// a layout change; `RevealIncrement` closes it again.
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct CommitmentAccount {
+    /// Always `CommitmentAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    /// `hash_nonce` of the secret nonce
+    pub hash: [u8; 32],
+    /// Canonical bump of the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for CommitmentAccount {
+    const DISCRIMINATOR: [u8; 8] = [155, 206, 108, 147, 168, 110, 100, 181];
+}
+
+impl CommitmentAccount {
+    /// Serialized size: discriminator, the hash and the bump
+    pub const LEN: usize = 8 + 32 + 1;
+
+    /// Commitment for `nonce`: keccak of its little-endian bytes
+    pub fn hash_nonce(nonce: u64) -> [u8; 32] {
+        solana_keccak_hasher::hashv(&[&nonce.to_le_bytes()]).to_bytes()
+    }
+}
+
+/// Program-wide totals, at the single PDA `["stats"]`
+///
+/// Shared by every counter: `IncrementCounter` bumps it alongside the counter
+/// whenever the caller passes it in.
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct StatsAccount {
+    /// Always `StatsAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    /// Increments made with this account passed to `IncrementCounter`
+    pub total_increments: u64,
+    /// Canonical bump of the PDA
+    pub bump: u8,
+}
+
+impl Discriminator for StatsAccount {
+    const DISCRIMINATOR: [u8; 8] = [84, 176, 18, 54, 180, 227, 63, 42];
+}
+
+impl StatsAccount {
+    /// Serialized size: discriminator, the total and the bump
+    pub const LEN: usize = 8 + 8 + 1;
+}
+
+/// Counter with a human-readable, variable-length name
+///
+/// The name is a borsh `String` (u32 length prefix + UTF-8 bytes), so unlike the
+/// other layouts the account size depends on the data. Renaming to a different
+/// length would need the account resized (and rent topped up) first, like
+/// legacy counters are when they migrate.
+#[derive(CodamaAccount, BorshSerialize, BorshDeserialize, Debug)]
+pub struct NamedCounterAccount {
+    /// Always `NamedCounterAccount::DISCRIMINATOR`
+    pub discriminator: [u8; 8],
+    pub count: u64,
+    pub name: String,
+}
+
+impl Discriminator for NamedCounterAccount {
+    const DISCRIMINATOR: [u8; 8] = [11, 13, 47, 110, 201, 70, 12, 119];
+}
+
+impl NamedCounterAccount {
+    /// Longest name accepted, in bytes
+    pub const MAX_NAME_LEN: usize = 32;
+
+    /// Serialized size for a name of `name_len` bytes: discriminator, count, length prefix, name
+    pub fn space(name_len: usize) -> usize {
+        8 + 8 + 4 + name_len
+    }
+}
+
+/// The Anchor program's `Counter` account, read from native code without its crate
+///
+/// Layout: `0..8` discriminator, `8..16` count, `16..48` authority. Checking the
+/// discriminator tells a `Counter` apart from any other account the Anchor program
+/// (or anyone else) owns.
+#[derive(Debug, PartialEq, Eq)]
+pub struct AnchorCounterView {
+    pub count: u64,
+    pub authority: Pubkey,
+}
+
+impl AnchorCounterView {
+    /// First 8 bytes of `sha256("account:Counter")`
+    pub const DISCRIMINATOR: [u8; 8] = [255, 176, 4, 245, 188, 253, 124, 25];
+
+    /// Serialized size: discriminator, count, authority
+    pub const LEN: usize = 8 + 8 + 32;
+
+    /// Decode a `Counter`, rejecting data with another discriminator or too short
+    pub fn unpack(data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() < Self::LEN || data[..8] != Self::DISCRIMINATOR {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Self {
+            count: u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            authority: Pubkey::try_from(&data[16..48]).unwrap(),
+        })
+    }
+}
+
+impl Sealed for CounterAccount {}
+
+/// SPL-style fixed layout, a third serialization approach next to borsh and Anchor
+///
+/// Layout (little-endian):
+/// - `0..8`: count (u64)
+/// - `8..16`: step (u64)
+/// - `16..24`: last_updated (i64)
+/// - `24..32`: max_value (u64)
+/// - `32..64`: authority (Pubkey)
+/// - `64`: enabled (bool, 0 or 1)
+/// - `65..73`: snapshot (u64)
+/// - `73`: delegate tag (0 = none, 1 = some)
+/// - `74..106`: delegate (Pubkey, zeroed when there is none)
+/// - `106..114`: last_epoch (u64)
+/// - `114`: overflow_behavior (u8)
+/// - `115..123`: nonce (u64)
+///
+/// The bytes are identical to the borsh encoding, so both can read the same account.
+impl Pack for CounterAccount {
+    const LEN: usize = CounterAccount::LEN;
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        dst[0..8].copy_from_slice(&self.count.to_le_bytes());
+        dst[8..16].copy_from_slice(&self.step.to_le_bytes());
+        dst[16..24].copy_from_slice(&self.last_updated.to_le_bytes());
+        dst[24..32].copy_from_slice(&self.max_value.to_le_bytes());
+        dst[32..64].copy_from_slice(self.authority.as_ref());
+        dst[64] = self.enabled as u8;
+        dst[65..73].copy_from_slice(&self.snapshot.to_le_bytes());
+        match self.delegate {
+            Some(delegate) => {
+                dst[73] = 1;
+                dst[74..106].copy_from_slice(delegate.as_ref());
+            }
+            None => dst[73..106].fill(0),
+        }
+        dst[106..114].copy_from_slice(&self.last_epoch.to_le_bytes());
+        dst[114] = self.overflow_behavior;
+        dst[115..123].copy_from_slice(&self.nonce.to_le_bytes());
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let read_bytes = |range: std::ops::Range<usize>| -> Result<[u8; 8], ProgramError> {
+            src.get(range)
+                .and_then(|bytes| bytes.try_into().ok())
+                .ok_or(ProgramError::InvalidAccountData)
+        };
+        let authority = src
+            .get(32..64)
+            .and_then(|bytes| Pubkey::try_from(bytes).ok())
+            .ok_or(ProgramError::InvalidAccountData)?;
+        let enabled = match src.get(64) {
+            Some(0) => false,
+            Some(1) => true,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+        let delegate = match src.get(73) {
+            Some(0) => None,
+            Some(1) => Some(
+                src.get(74..106)
+                    .and_then(|bytes| Pubkey::try_from(bytes).ok())
+                    .ok_or(ProgramError::InvalidAccountData)?,
+            ),
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        Ok(Self {
+            count: u64::from_le_bytes(read_bytes(0..8)?),
+            step: u64::from_le_bytes(read_bytes(8..16)?),
+            last_updated: i64::from_le_bytes(read_bytes(16..24)?),
+            max_value: u64::from_le_bytes(read_bytes(24..32)?),
+            authority,
+            enabled,
+            snapshot: u64::from_le_bytes(read_bytes(65..73)?),
+            delegate,
+            last_epoch: u64::from_le_bytes(read_bytes(106..114)?),
+            overflow_behavior: *src.get(114).ok_or(ProgramError::InvalidAccountData)?,
+            nonce: u64::from_le_bytes(read_bytes(115..123)?),
+        })
+    }
+}
+
+/// Zero-copy view of a `CounterAccount` in the current layout
+///
+/// A byte-for-byte `#[repr(C, packed)]` mirror of the borsh layout, so account data
+/// can be cast with bytemuck and fields read and written in place instead of being
+/// deserialized and serialized again. `bool` and `Option` are not `Pod`, so `enabled`
+/// and the delegate tag are kept as raw bytes.
+#[repr(C, packed)]
+#[derive(Pod, Zeroable, Clone, Copy, Debug)]
+pub struct CounterAccountPod {
+    pub count: u64,
+    pub step: u64,
+    pub last_updated: i64,
+    pub max_value: u64,
+    pub authority: Pubkey,
+    /// 1 if enabled, 0 if paused
+    pub enabled: u8,
+    pub snapshot: u64,
+    /// 1 if `delegate` is set, 0 if it is all zeros
+    pub delegate_tag: u8,
+    pub delegate: Pubkey,
+    pub last_epoch: u64,
+    pub overflow_behavior: u8,
+    pub nonce: u64,
+}
+
+impl CounterAccountPod {
+    /// View the counter at the start of `data`, or `None` for a legacy, shorter layout
+    pub fn from_bytes(data: &[u8]) -> Option<&Self> {
+        data.get(..CounterAccount::LEN).map(bytemuck::from_bytes)
+    }
+
+    /// Mutable counterpart of [`CounterAccountPod::from_bytes`]
+    pub fn from_bytes_mut(data: &mut [u8]) -> Option<&mut Self> {
+        data.get_mut(..CounterAccount::LEN)
+            .map(bytemuck::from_bytes_mut)
+    }
+
+    /// Amount a single increment adds, treating an unset step as 1
+    pub fn increment_step(&self) -> u64 {
+        if self.step == 0 {
+            1
+        } else {
+            self.step
+        }
+    }
 }
@@ -0,0 +1,340 @@
+//! Shared LiteSVM setup for the program tests
+//!
+//! Both programs must be built first (`cargo build-sbf` and `anchor build`).
+
+use self::types::{AccountMeta, Instruction, Pubkey};
+use crate::{AnchorIx, CounterAccount, CounterInstruction};
+use litesvm::LiteSVM;
+use solana_sdk::{
+    account::ReadableAccount,
+    message::Message,
+    signature::{read_keypair_file, Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// The common types the tests need from both Solana crates, in one place
+///
+/// LiteSVM speaks solana-sdk while the program is built on solana-program, and
+/// across major versions their `Pubkey`s are distinct types. Tests import them
+/// from here, so a version bump only touches this module; convert between the
+/// two with `new_from_array(key.to_bytes())`.
+pub mod types {
+    pub use solana_program::pubkey::Pubkey as ProgramPubkey;
+    pub use solana_sdk::{
+        instruction::{AccountMeta, Instruction},
+        pubkey::Pubkey,
+    };
+}
+
+// System Program ID - well-known constant "11111111111111111111111111111111"
+pub const SYSTEM_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("11111111111111111111111111111111");
+
+pub const NATIVE_PROGRAM_KEYPAIR: &str = "target/deploy/counter_program-keypair.json";
+
+/// Env var that overrides where the native program keypair is read from
+pub const NATIVE_PROGRAM_KEYPAIR_ENV: &str = "COUNTER_PROGRAM_KEYPAIR";
+pub const NATIVE_PROGRAM_SO: &str = "target/deploy/counter_program.so";
+pub const ANCHOR_PROGRAM_KEYPAIR: &str = "anchor-counter/target/deploy/anchor_counter-keypair.json";
+pub const ANCHOR_PROGRAM_SO: &str = "anchor-counter/target/deploy/anchor_counter.so";
+
+/// Compute Budget program ID - well-known constant
+pub const COMPUTE_BUDGET_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("ComputeBudget111111111111111111111111111111");
+
+/// SPL Memo program ID (v3), preloaded by LiteSVM
+pub const MEMO_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// Create a LiteSVM with a funded payer and both the native and Anchor programs deployed
+///
+/// Returns `(svm, payer, native_program_id, anchor_program_id)`.
+pub fn setup_svm() -> (LiteSVM, Keypair, Pubkey, Pubkey) {
+    setup_svm_at(native_program_id())
+}
+
+/// Same as [`setup_svm`], but deploys the native program at `native_program_id`
+pub fn setup_svm_at(native_program_id: Pubkey) -> (LiteSVM, Keypair, Pubkey, Pubkey) {
+    let (mut svm, payer) = funded_svm();
+    svm.add_program_from_file(native_program_id, NATIVE_PROGRAM_SO)
+        .unwrap();
+
+    let anchor_program_id = read_keypair_file(ANCHOR_PROGRAM_KEYPAIR)
+        .expect("Anchor program keypair file not found")
+        .pubkey();
+    svm.add_program_from_file(anchor_program_id, ANCHOR_PROGRAM_SO)
+        .unwrap();
+
+    (svm, payer, native_program_id, anchor_program_id)
+}
+
+/// Path of the native program keypair used to derive its program ID
+///
+/// Set `COUNTER_PROGRAM_KEYPAIR` to point at the keypair when building with a custom
+/// `CARGO_TARGET_DIR` or running the tests from another directory. Without it, the
+/// default `target/deploy/counter_program-keypair.json` is used.
+pub fn native_program_keypair_path() -> String {
+    resolve_keypair_path(std::env::var(NATIVE_PROGRAM_KEYPAIR_ENV).ok())
+}
+
+/// Pick the overridden keypair path if one is set, otherwise the default
+pub fn resolve_keypair_path(env_value: Option<String>) -> String {
+    env_value
+        .filter(|path| !path.is_empty())
+        .unwrap_or_else(|| NATIVE_PROGRAM_KEYPAIR.to_string())
+}
+
+/// Program ID of the native program, read from its deploy keypair
+pub fn native_program_id() -> Pubkey {
+    read_keypair_file(native_program_keypair_path())
+        .expect("Program keypair file not found")
+        .pubkey()
+}
+
+/// Program ID the Codama-generated client invokes
+///
+/// Codama takes the address from `[package.metadata.solana] program-id` in the manifest,
+/// which is not the `declare_id!` address nor the one derived from the deploy keypair.
+/// The generated CPI builders always invoke this address, so when the program is
+/// deployed at the keypair address a Codama CPI hits an account with no program
+/// behind it ("program ID mismatch"). Tests that go through the Codama CPI builders
+/// deploy the program here instead.
+pub fn codama_program_id() -> Pubkey {
+    Pubkey::new_from_array(codama_client::programs::COUNTER_PROGRAM_ID.to_bytes())
+}
+
+fn funded_svm() -> (LiteSVM, Keypair) {
+    let mut svm = LiteSVM::new();
+
+    let payer = Keypair::new();
+    svm.airdrop(&payer.pubkey(), 2_000_000_000).unwrap();
+
+    (svm, payer)
+}
+
+/// Create a LiteSVM with a funded payer and only the native program deployed
+pub fn setup_native_svm() -> (LiteSVM, Keypair, Pubkey) {
+    setup_native_svm_at(native_program_id())
+}
+
+/// Create a LiteSVM with the native program deployed at the Codama client's program ID
+///
+/// See [`codama_program_id`] for why this is needed.
+pub fn setup_codama_svm() -> (LiteSVM, Keypair, Pubkey) {
+    setup_native_svm_at(codama_program_id())
+}
+
+fn setup_native_svm_at(program_id: Pubkey) -> (LiteSVM, Keypair, Pubkey) {
+    let (mut svm, payer) = funded_svm();
+    svm.add_program_from_file(program_id, NATIVE_PROGRAM_SO)
+        .unwrap();
+
+    (svm, payer, program_id)
+}
+
+/// Build a Compute Budget `SetComputeUnitLimit` instruction (tag 2 + u32 units)
+pub fn set_compute_unit_limit_ix(units: u32) -> Instruction {
+    let mut data = vec![2];
+    data.extend_from_slice(&units.to_le_bytes());
+    Instruction::new_with_bytes(COMPUTE_BUDGET_PROGRAM_ID, &data, vec![])
+}
+
+/// Send `transaction` and return its program logs, panicking with them if it fails
+pub fn run_and_get_logs(svm: &mut LiteSVM, transaction: Transaction) -> Vec<String> {
+    match svm.send_transaction(transaction) {
+        Ok(meta) => meta.logs,
+        Err(failed) => panic!(
+            "Transaction failed: {:?}\nLogs:\n{}",
+            failed.err,
+            failed.meta.logs.join("\n")
+        ),
+    }
+}
+
+/// Build an `InitializeCounter` instruction for `counter`, paid for by `payer`
+pub fn initialize_ix(
+    program_id: Pubkey,
+    counter: &Pubkey,
+    payer: &Pubkey,
+    initial_value: u64,
+) -> Instruction {
+    let data = borsh::to_vec(&CounterInstruction::InitializeCounter { initial_value })
+        .expect("Failed to serialize instruction");
+    Instruction::new_with_bytes(
+        program_id,
+        &data,
+        vec![
+            AccountMeta::new(*counter, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+/// Initialize a native counter with `initial_value` and return its keypair
+pub fn init_counter(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    program_id: Pubkey,
+    initial_value: u64,
+) -> Keypair {
+    let counter = Keypair::new();
+    let instruction = initialize_ix(
+        program_id,
+        &counter.pubkey(),
+        &payer.pubkey(),
+        initial_value,
+    );
+
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&[payer, &counter], message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Initialize counter transaction should succeed");
+
+    counter
+}
+
+/// Tracks the native counters a test creates and the count each should hold
+///
+/// Every change goes through the registry, so `verify_all` can check all of them
+/// at once and catch an instruction that touched a counter it shouldn't have.
+pub struct CounterRegistry {
+    program_id: Pubkey,
+    expected: Vec<(Pubkey, u64)>,
+}
+
+impl CounterRegistry {
+    pub fn new(program_id: Pubkey) -> Self {
+        Self {
+            program_id,
+            expected: Vec::new(),
+        }
+    }
+
+    /// Initialize a counter at `initial_value` and start tracking it
+    pub fn init(&mut self, svm: &mut LiteSVM, payer: &Keypair, initial_value: u64) -> Pubkey {
+        let counter = init_counter(svm, payer, self.program_id, initial_value).pubkey();
+        self.expected.push((counter, initial_value));
+        counter
+    }
+
+    /// Increment a tracked counter by one and expect the same of it
+    pub fn increment(&mut self, svm: &mut LiteSVM, payer: &Keypair, counter: &Pubkey) {
+        let data = borsh::to_vec(&CounterInstruction::IncrementCounter)
+            .expect("Failed to serialize instruction");
+        let instruction = Instruction::new_with_bytes(
+            self.program_id,
+            &data,
+            vec![AccountMeta::new(*counter, false)],
+        );
+        let message = Message::new(&[instruction], Some(&payer.pubkey()));
+        let transaction = Transaction::new(&[payer], message, svm.latest_blockhash());
+        svm.send_transaction(transaction)
+            .expect("Increment counter transaction should succeed");
+        // A repeat increment would otherwise be the same, already processed, transaction
+        svm.expire_blockhash();
+
+        let (_, expected) = self
+            .expected
+            .iter_mut()
+            .find(|(key, _)| key == counter)
+            .expect("Counter is not registered");
+        *expected += 1;
+    }
+
+    /// Assert every tracked counter holds the count the registry expects
+    #[track_caller]
+    pub fn verify_all(&self, svm: &LiteSVM) {
+        for (counter, expected) in &self.expected {
+            assert_counter_eq(svm, counter, *expected);
+        }
+    }
+}
+
+/// Build Anchor's `initialize_counter` instruction: discriminator + little-endian `initial_value`
+pub fn anchor_initialize_ix(
+    counter: Pubkey,
+    payer: Pubkey,
+    initial_value: u64,
+    anchor_program_id: Pubkey,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        anchor_program_id,
+        &AnchorIx::InitializeCounter { initial_value }.to_data(),
+        vec![
+            AccountMeta::new(counter, true),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+    )
+}
+
+/// Initialize an Anchor counter with `initial_value` and return its keypair
+pub fn init_anchor_counter(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    anchor_program_id: Pubkey,
+    initial_value: u64,
+) -> Keypair {
+    let counter = Keypair::new();
+    let instruction = anchor_initialize_ix(
+        counter.pubkey(),
+        payer.pubkey(),
+        initial_value,
+        anchor_program_id,
+    );
+
+    let message = Message::new(&[instruction], Some(&payer.pubkey()));
+    let transaction = Transaction::new(&[payer, &counter], message, svm.latest_blockhash());
+    svm.send_transaction(transaction)
+        .expect("Initialize Anchor counter transaction should succeed");
+
+    counter
+}
+
+/// Read and deserialize a native counter account
+pub fn read_counter(svm: &LiteSVM, counter: &Pubkey) -> CounterAccount {
+    let account = svm
+        .get_account(counter)
+        .expect("Counter account should exist");
+    CounterAccount::load(account.data()).expect("Failed to deserialize counter account")
+}
+
+/// Assert a native counter holds `expected`, reporting both values on failure
+#[track_caller]
+pub fn assert_counter_eq(svm: &LiteSVM, counter: &Pubkey, expected: u64) {
+    let actual = read_counter(svm, counter).count;
+    assert_eq!(
+        actual, expected,
+        "Counter {} holds {}, expected {}",
+        counter, actual, expected
+    );
+}
+
+/// Size of an Anchor counter: 8-byte discriminator + count (u64) + authority (Pubkey)
+pub const ANCHOR_COUNTER_LEN: usize = 8 + 8 + 32;
+
+/// Decode an Anchor counter's data into `(count, authority)`
+///
+/// Layout: `0..8` discriminator, `8..16` count, `16..48` authority. Returns `None`
+/// if the data is too short to hold both fields.
+pub fn decode_anchor_counter(data: &[u8]) -> Option<(u64, Pubkey)> {
+    if data.len() < ANCHOR_COUNTER_LEN {
+        return None;
+    }
+    let count = u64::from_le_bytes(data[8..16].try_into().ok()?);
+    let authority = Pubkey::try_from(&data[16..48]).ok()?;
+    Some((count, authority))
+}
+
+/// Read and decode an Anchor counter into `(count, authority)`
+pub fn read_anchor_counter(svm: &LiteSVM, counter: &Pubkey) -> (u64, Pubkey) {
+    let account = svm
+        .get_account(counter)
+        .expect("Anchor counter account should exist");
+    decode_anchor_counter(account.data()).expect("Anchor counter data too short")
+}
+
+/// Read the count of an Anchor counter
+pub fn read_anchor_count(svm: &LiteSVM, counter: &Pubkey) -> u64 {
+    read_anchor_counter(svm, counter).0
+}